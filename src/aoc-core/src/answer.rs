@@ -0,0 +1,605 @@
+use std::{
+    fmt::Display,
+    fs::OpenOptions,
+    io::{self, Write},
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use thiserror::Error;
+
+use crate::{
+    input_source::{AocClient, DiskCache},
+    InputError,
+};
+
+const LEDGER_FILENAME: &str = r"ledger.txt";
+
+// the wait time that is assumed, if it can't be read from the response
+const DEFAULT_WAIT_TIME: Duration = Duration::from_secs(60);
+
+/// Errors that can happen while submitting an answer.
+#[derive(Error, Debug)]
+pub enum AnswerError {
+    /// There was an error while talking to adventofcode.com.
+    #[error(transparent)]
+    Input(#[from] InputError),
+
+    /// There was an I/O error while reading or writing the ledger file.
+    #[error("There was an I/O error while accessing the ledger file '{1}'.")]
+    AccessLedger(#[source] io::Error, String),
+
+    /// A line of the ledger file could not be parsed.
+    #[error("The ledger file contains an invalid line: '{0}'.")]
+    InvalidLedgerEntry(String),
+
+    /// The answer was already submitted and was wrong.
+    #[error("The answer '{1}' for part {0} was already submitted and is wrong.")]
+    KnownWrong(u32, String),
+
+    /// The answer is not lower than an answer that is known to be too high.
+    #[error("The answer '{1}' for part {0} is too high, because '{2}' is already too high.")]
+    KnownTooHigh(u32, String, String),
+
+    /// The answer is not higher than an answer that is known to be too low.
+    #[error("The answer '{1}' for part {0} is too low, because '{2}' is already too low.")]
+    KnownTooLow(u32, String, String),
+
+    /// The part was already solved with another answer.
+    #[error("Part {0} was already solved with the answer '{1}'.")]
+    AlreadySolved(u32, String),
+
+    /// The response of adventofcode.com could not be understood.
+    #[error("The response to the answer for the puzzle of day {1} for year {0} could not be understood.")]
+    UnexpectedResponse(u32, u32),
+}
+
+/// The outcome of submitting an answer.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SubmitOutcome {
+    /// The answer is correct.
+    Correct,
+
+    /// The answer is wrong and too high.
+    TooHigh,
+
+    /// The answer is wrong and too low.
+    TooLow,
+
+    /// The answer is wrong.
+    Wrong,
+
+    /// An answer was given too recently. The answer was not checked and can
+    /// be submitted again after the wait time. If the wait time can't be read
+    /// from the response, one minute is assumed.
+    RateLimited(Duration),
+
+    /// The part is either already solved or not unlocked yet.
+    WrongLevel,
+}
+
+impl SubmitOutcome {
+    /// Parses the HTML page that adventofcode.com answers with after an
+    /// answer was posted.
+    ///
+    /// # Arguments
+    ///
+    /// - `html` - The HTML page of the response.
+    pub fn from_response(html: &str) -> Option<Self> {
+        if html.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if html.contains("That's not the right answer") {
+            if html.contains("your answer is too high") {
+                Some(Self::TooHigh)
+            } else if html.contains("your answer is too low") {
+                Some(Self::TooLow)
+            } else {
+                Some(Self::Wrong)
+            }
+        } else if html.contains("You gave an answer too recently") {
+            Some(Self::RateLimited(parse_wait_time(html).unwrap_or(DEFAULT_WAIT_TIME)))
+        } else if html.contains("You don't seem to be solving the right level") {
+            Some(Self::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    fn to_token(self) -> String {
+        match self {
+            Self::Correct => "correct".to_string(),
+            Self::TooHigh => "too_high".to_string(),
+            Self::TooLow => "too_low".to_string(),
+            Self::Wrong => "wrong".to_string(),
+            Self::RateLimited(wait) => format!("rate_limited:{}", wait.as_secs()),
+            Self::WrongLevel => "wrong_level".to_string(),
+        }
+    }
+
+    fn from_token(token: &str) -> Option<Self> {
+        match token {
+            "correct" => Some(Self::Correct),
+            "too_high" => Some(Self::TooHigh),
+            "too_low" => Some(Self::TooLow),
+            "wrong" => Some(Self::Wrong),
+            "wrong_level" => Some(Self::WrongLevel),
+            _ => token.strip_prefix("rate_limited:")
+                .and_then(|secs| secs.parse().ok())
+                .map(|secs| Self::RateLimited(Duration::from_secs(secs))),
+        }
+    }
+
+    /// Returns `true` if the outcome says that the answer is wrong.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Self::TooHigh | Self::TooLow | Self::Wrong)
+    }
+}
+
+/// Parses the wait time of a message like `You have 1m 5s left to wait.`.
+fn parse_wait_time(html: &str) -> Option<Duration> {
+    let start = html.find("You have ")? + "You have ".len();
+    let end = start + html[start..].find(" left to wait")?;
+
+    let mut secs = 0;
+
+    for token in html[start..end].split_whitespace() {
+        if let Some(minutes) = token.strip_suffix('m') {
+            secs += minutes.parse::<u64>().ok()? * 60;
+        } else if let Some(seconds) = token.strip_suffix('s') {
+            secs += seconds.parse::<u64>().ok()?;
+        } else {
+            return None;
+        }
+    }
+
+    Some(Duration::from_secs(secs))
+}
+
+/// An attempt to answer a part of a puzzle.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LedgerEntry {
+    /// The time of the attempt in seconds since the Unix epoch.
+    pub timestamp: u64,
+
+    /// The part of the puzzle.
+    pub part: u32,
+
+    /// The answer that was submitted.
+    pub answer: String,
+
+    /// The outcome of the attempt.
+    pub outcome: SubmitOutcome,
+}
+
+impl LedgerEntry {
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}",
+            self.timestamp, self.part, self.outcome.to_token(), escape_field(&self.answer))
+    }
+
+    fn from_line(line: &str) -> Option<Self> {
+        let mut tokens = line.splitn(4, '\t');
+
+        Some(Self {
+            timestamp: tokens.next()?.parse().ok()?,
+            part: tokens.next()?.parse().ok()?,
+            outcome: SubmitOutcome::from_token(tokens.next()?)?,
+            answer: unescape_field(tokens.next()?)?,
+        })
+    }
+}
+
+/// Escapes backslashes, tabs and line breaks, so `field` fits into a single
+/// column of the ledger.
+fn escape_field(field: &str) -> String {
+    let mut result = String::with_capacity(field.len());
+
+    for c in field.chars() {
+        match c {
+            '\\' => result.push_str("\\\\"),
+            '\t' => result.push_str("\\t"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            _ => result.push(c),
+        }
+    }
+
+    result
+}
+
+/// Reverts [`escape_field`]. Returns `None` for an unknown escape sequence.
+fn unescape_field(field: &str) -> Option<String> {
+    let mut result = String::with_capacity(field.len());
+    let mut chars = field.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next()? {
+                '\\' => result.push('\\'),
+                't' => result.push('\t'),
+                'n' => result.push('\n'),
+                'r' => result.push('\r'),
+                _ => return None,
+            }
+        } else {
+            result.push(c);
+        }
+    }
+
+    Some(result)
+}
+
+/// The ledger of all answers that were submitted for a puzzle. It is stored
+/// in `ledger.txt` next to the input of the puzzle, one attempt per line.
+#[derive(Debug, Clone)]
+pub struct AnswerLedger {
+    path: PathBuf,
+    entries: Vec<LedgerEntry>,
+}
+
+impl AnswerLedger {
+    /// Opens the ledger file at `path`. A missing file is an empty ledger.
+    ///
+    /// # Arguments
+    ///
+    /// - `path` - The path of the ledger file.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, AnswerError> {
+        let path = path.into();
+
+        let entries = if path.exists() {
+            std::fs::read_to_string(&path)
+                .map_err(|e| AnswerError::AccessLedger(e, format!("{:?}", path)))?
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(|line| LedgerEntry::from_line(line)
+                    .ok_or(AnswerError::InvalidLedgerEntry(line.to_string())))
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            vec![]
+        };
+
+        Ok(Self { path, entries })
+    }
+
+    /// Returns all attempts in the order they were made.
+    pub fn entries(&self) -> &[LedgerEntry] {
+        &self.entries
+    }
+
+    /// Returns the correct answer of `part`, if it is known.
+    ///
+    /// # Arguments
+    ///
+    /// - `part` - The part of the puzzle.
+    pub fn correct_answer(&self, part: u32) -> Option<&str> {
+        self.entries.iter()
+            .find(|entry| entry.part == part && entry.outcome == SubmitOutcome::Correct)
+            .map(|entry| entry.answer.as_str())
+    }
+
+    /// Checks the `answer` for `part` against the known attempts without
+    /// submitting it. Returns `Ok(Some(SubmitOutcome::Correct))` if the answer
+    /// is known to be correct and `Ok(None)` if the answer has to be
+    /// submitted. An answer that is known to be wrong, or that is outside of
+    /// the bounds of known too high and too low answers, is an error.
+    ///
+    /// # Arguments
+    ///
+    /// - `part` - The part of the puzzle.
+    /// - `answer` - The answer to check.
+    pub fn check(&self, part: u32, answer: &str) -> Result<Option<SubmitOutcome>, AnswerError> {
+        if let Some(correct) = self.correct_answer(part) {
+            return if correct == answer {
+                Ok(Some(SubmitOutcome::Correct))
+            } else {
+                Err(AnswerError::AlreadySolved(part, correct.to_string()))
+            };
+        }
+
+        let attempts = || self.entries.iter().filter(|entry| entry.part == part);
+
+        if attempts().any(|entry| entry.answer == answer && entry.outcome.is_wrong()) {
+            return Err(AnswerError::KnownWrong(part, answer.to_string()));
+        }
+
+        if let Ok(value) = answer.parse::<i64>() {
+            let bound = |outcome: SubmitOutcome| attempts()
+                .filter(move |entry| entry.outcome == outcome)
+                .filter_map(|entry| entry.answer.parse::<i64>().ok());
+
+            if let Some(too_high) = bound(SubmitOutcome::TooHigh).min() {
+                if value >= too_high {
+                    return Err(AnswerError::KnownTooHigh(
+                        part, answer.to_string(), too_high.to_string()));
+                }
+            }
+
+            if let Some(too_low) = bound(SubmitOutcome::TooLow).max() {
+                if value <= too_low {
+                    return Err(AnswerError::KnownTooLow(
+                        part, answer.to_string(), too_low.to_string()));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Records an attempt and appends it to the ledger file.
+    ///
+    /// # Arguments
+    ///
+    /// - `part` - The part of the puzzle.
+    /// - `answer` - The answer that was submitted.
+    /// - `outcome` - The outcome of the attempt.
+    pub fn record(&mut self, part: u32, answer: &str, outcome: SubmitOutcome)
+        -> Result<(), AnswerError>
+    {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+
+        let entry = LedgerEntry {
+            timestamp,
+            part,
+            answer: answer.to_string(),
+            outcome,
+        };
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| writeln!(file, "{}", entry.to_line()))
+            .map_err(|e| AnswerError::AccessLedger(e, format!("{:?}", self.path)))?;
+
+        self.entries.push(entry);
+
+        Ok(())
+    }
+}
+
+/// Submits answers to adventofcode.com and keeps the ledger of each puzzle in
+/// the on-disk cache.
+#[derive(Debug, Clone)]
+pub struct AnswerSubmitter {
+    cache: DiskCache,
+    client: AocClient,
+}
+
+impl AnswerSubmitter {
+    /// Creates a new `AnswerSubmitter`.
+    ///
+    /// # Arguments
+    ///
+    /// - `cache` - The cache to keep the ledgers in.
+    /// - `client` - The client to submit the answers with.
+    pub fn new(cache: DiskCache, client: AocClient) -> Self {
+        Self { cache, client }
+    }
+
+    /// Creates a new `AnswerSubmitter` that uses the data directory of the
+    /// user and adventofcode.com.
+    pub fn data_dir() -> Result<Self, AnswerError> {
+        Ok(Self::new(DiskCache::data_dir()?, AocClient::new()))
+    }
+
+    /// Returns the ledger of the puzzle of `year` and `day`.
+    ///
+    /// # Arguments
+    ///
+    /// - `year` - The year of the puzzle.
+    /// - `day` - The day of the puzzle.
+    pub fn ledger(&self, year: u32, day: u32) -> Result<AnswerLedger, AnswerError> {
+        let puzzle_dir = self.cache.provision_puzzle_dir(year, day)?;
+
+        AnswerLedger::open(puzzle_dir.join(LEDGER_FILENAME))
+    }
+
+    /// Submits the `answer` for `part` of the puzzle of `year` and `day`. The
+    /// answer is checked against the ledger first and is only posted if the
+    /// ledger does not know the outcome. Every posted answer is recorded in
+    /// the ledger.
+    ///
+    /// # Arguments
+    ///
+    /// - `year` - The year of the puzzle.
+    /// - `day` - The day of the puzzle.
+    /// - `part` - The part of the puzzle, i. e. 1 or 2.
+    /// - `answer` - The answer to submit.
+    pub fn submit(&self, year: u32, day: u32, part: u32, answer: impl Display)
+        -> Result<SubmitOutcome, AnswerError>
+    {
+        let answer = answer.to_string();
+        let mut ledger = self.ledger(year, day)?;
+
+        if let Some(outcome) = ledger.check(part, &answer)? {
+            return Ok(outcome);
+        }
+
+        let response = self.client.post_answer(year, day, part, &answer)?;
+        let outcome = SubmitOutcome::from_response(&response)
+            .ok_or(AnswerError::UnexpectedResponse(year, day))?;
+
+        ledger.record(part, &answer, outcome)?;
+
+        Ok(outcome)
+    }
+}
+
+/// Submits the `answer` for `part` of the puzzle of `year` and `day` to
+/// adventofcode.com. The ledger is kept in the data directory of the user.
+///
+/// # Arguments
+///
+/// - `year` - The year of the puzzle.
+/// - `day` - The day of the puzzle.
+/// - `part` - The part of the puzzle, i. e. 1 or 2.
+/// - `answer` - The answer to submit.
+pub fn submit_answer(year: u32, day: u32, part: u32, answer: impl Display)
+    -> Result<SubmitOutcome, AnswerError>
+{
+    AnswerSubmitter::data_dir()?.submit(year, day, part, answer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp_dir::TempDir;
+
+    fn temp_ledger(dir: &TempDir) -> AnswerLedger {
+        AnswerLedger::open(dir.join(LEDGER_FILENAME)).unwrap()
+    }
+
+    #[test]
+    fn from_response_should_parse_correct() {
+        assert_eq!(
+            Some(SubmitOutcome::Correct),
+            SubmitOutcome::from_response("<p>That's the right answer!  You are one gold star closer.</p>"));
+    }
+
+    #[test]
+    fn from_response_should_parse_too_high() {
+        assert_eq!(
+            Some(SubmitOutcome::TooHigh),
+            SubmitOutcome::from_response("<p>That's not the right answer; your answer is too high.</p>"));
+    }
+
+    #[test]
+    fn from_response_should_parse_too_low() {
+        assert_eq!(
+            Some(SubmitOutcome::TooLow),
+            SubmitOutcome::from_response("<p>That's not the right answer; your answer is too low.</p>"));
+    }
+
+    #[test]
+    fn from_response_should_parse_wrong() {
+        assert_eq!(
+            Some(SubmitOutcome::Wrong),
+            SubmitOutcome::from_response("<p>That's not the right answer.  If you're stuck, ...</p>"));
+    }
+
+    #[test]
+    fn from_response_should_parse_rate_limited_with_wait_time() {
+        assert_eq!(
+            Some(SubmitOutcome::RateLimited(Duration::from_secs(65))),
+            SubmitOutcome::from_response(
+                "<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.</p>"));
+    }
+
+    #[test]
+    fn from_response_should_assume_one_minute_for_unreadable_wait_time() {
+        assert_eq!(
+            Some(SubmitOutcome::RateLimited(Duration::from_secs(60))),
+            SubmitOutcome::from_response(
+                "<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have a while left to wait.</p>"));
+    }
+
+    #[test]
+    fn from_response_should_return_none_for_unknown_page() {
+        assert_eq!(None, SubmitOutcome::from_response("<p>Hello</p>"));
+    }
+
+    #[test]
+    fn ledger_entry_should_survive_round_trip() {
+        let entry = LedgerEntry {
+            timestamp: 1733011200,
+            part: 2,
+            answer: "4,6,3".to_string(),
+            outcome: SubmitOutcome::RateLimited(Duration::from_secs(30)),
+        };
+
+        assert_eq!(Some(entry.clone()), LedgerEntry::from_line(&entry.to_line()));
+    }
+
+    #[test]
+    fn ledger_entry_should_escape_tabs_and_line_breaks() {
+        let entry = LedgerEntry {
+            timestamp: 1733011200,
+            part: 1,
+            answer: "a\tb\nc\\n".to_string(),
+            outcome: SubmitOutcome::Wrong,
+        };
+
+        let line = entry.to_line();
+
+        assert!(!line.contains('\n'));
+        assert_eq!(4, line.split('\t').count());
+        assert_eq!(Some(entry), LedgerEntry::from_line(&line));
+    }
+
+    #[test]
+    fn check_should_refuse_known_wrong_answer() {
+        let dir = TempDir::new("ledger-known-wrong");
+        let mut ledger = temp_ledger(&dir);
+
+        ledger.record(1, "abc", SubmitOutcome::Wrong).unwrap();
+
+        assert!(matches!(ledger.check(1, "abc"), Err(AnswerError::KnownWrong(1, _))));
+        assert!(matches!(ledger.check(2, "abc"), Ok(None)));
+    }
+
+    #[test]
+    fn check_should_refuse_answers_outside_of_known_bounds() {
+        let dir = TempDir::new("ledger-bounds");
+        let mut ledger = temp_ledger(&dir);
+
+        ledger.record(1, "100", SubmitOutcome::TooHigh).unwrap();
+        ledger.record(1, "50", SubmitOutcome::TooLow).unwrap();
+
+        assert!(matches!(ledger.check(1, "120"), Err(AnswerError::KnownTooHigh(1, _, _))));
+        assert!(matches!(ledger.check(1, "101"), Err(AnswerError::KnownTooHigh(1, _, _))));
+        assert!(matches!(ledger.check(1, "10"), Err(AnswerError::KnownTooLow(1, _, _))));
+        assert!(matches!(ledger.check(1, "75"), Ok(None)));
+    }
+
+    #[test]
+    fn check_should_not_refuse_rate_limited_answer() {
+        let dir = TempDir::new("ledger-rate-limited");
+        let mut ledger = temp_ledger(&dir);
+
+        ledger.record(1, "75", SubmitOutcome::RateLimited(Duration::from_secs(30))).unwrap();
+
+        assert!(matches!(ledger.check(1, "75"), Ok(None)));
+    }
+
+    #[test]
+    fn check_should_return_correct_for_known_correct_answer() {
+        let dir = TempDir::new("ledger-correct");
+        let mut ledger = temp_ledger(&dir);
+
+        ledger.record(1, "75", SubmitOutcome::Correct).unwrap();
+
+        assert!(matches!(ledger.check(1, "75"), Ok(Some(SubmitOutcome::Correct))));
+        assert!(matches!(ledger.check(1, "76"), Err(AnswerError::AlreadySolved(1, _))));
+    }
+
+    #[test]
+    fn open_should_read_recorded_entries() {
+        let dir = TempDir::new("ledger-reopen");
+        let mut ledger = temp_ledger(&dir);
+
+        ledger.record(1, "100", SubmitOutcome::TooHigh).unwrap();
+        ledger.record(1, "75", SubmitOutcome::Correct).unwrap();
+
+        let reopened = AnswerLedger::open(&ledger.path).unwrap();
+
+        assert_eq!(ledger.entries(), reopened.entries());
+    }
+
+    #[test]
+    fn open_should_read_answers_with_tabs_and_line_breaks() {
+        let dir = TempDir::new("ledger-escaped");
+        let mut ledger = temp_ledger(&dir);
+
+        ledger.record(1, "a\tb", SubmitOutcome::Wrong).unwrap();
+        ledger.record(2, "line 1\nline 2", SubmitOutcome::Correct).unwrap();
+
+        let reopened = AnswerLedger::open(&ledger.path).unwrap();
+
+        assert_eq!(ledger.entries(), reopened.entries());
+        assert_eq!(Some("line 1\nline 2"), reopened.correct_answer(2));
+    }
+}
@@ -0,0 +1,212 @@
+use std::{
+    collections::BTreeMap,
+    io,
+    path::{Path, PathBuf},
+};
+
+use thiserror::Error;
+
+use crate::{input_source::DiskCache, InputError};
+
+const ANSWERS_FILENAME: &str = r"answers.toml";
+
+/// Errors that can happen while reading or writing the known-good answers.
+#[derive(Error, Debug)]
+pub enum AnswerStoreError {
+    /// There was an error with the data directory.
+    #[error(transparent)]
+    Input(#[from] InputError),
+
+    /// There was an I/O error while reading or writing the answers file.
+    #[error("There was an I/O error while accessing the answers file '{1}'.")]
+    AccessAnswers(#[source] io::Error, String),
+
+    /// The answers file could not be parsed.
+    #[error("The answers file '{1}' is invalid.")]
+    InvalidAnswers(#[source] toml::de::Error, String),
+
+    /// The answers could not be written as TOML.
+    #[error("The answers could not be written to the answers file '{1}'.")]
+    WriteAnswers(#[source] toml::ser::Error, String),
+}
+
+/// The result of checking an answer against the known-good answer.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Verification {
+    /// The answer is the known-good answer.
+    Match,
+
+    /// The answer differs from the known-good answer.
+    Mismatch(String),
+
+    /// There is no known-good answer for the part yet.
+    Unknown,
+}
+
+/// The known-good answers per year, day and part, stored as TOML:
+///
+/// ```toml
+/// [2024.4]
+/// 1 = "2406"
+/// 2 = "1807"
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnswerStore {
+    path: PathBuf,
+    answers: BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>,
+}
+
+impl AnswerStore {
+    /// Opens the answers file at `path`. The file is created on the first
+    /// save, if it does not exist yet.
+    ///
+    /// # Arguments
+    ///
+    /// - `path` - The path of the answers file.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, AnswerStoreError> {
+        let path = path.into();
+
+        let answers = if path.exists() {
+            let toml = std::fs::read_to_string(&path)
+                .map_err(|e| AnswerStoreError::AccessAnswers(e, format!("{:?}", path)))?;
+
+            toml::from_str(&toml)
+                .map_err(|e| AnswerStoreError::InvalidAnswers(e, format!("{:?}", path)))?
+        } else {
+            BTreeMap::new()
+        };
+
+        Ok(Self { path, answers })
+    }
+
+    /// Opens `answers.toml` in the data directory of the user.
+    pub fn data_dir() -> Result<Self, AnswerStoreError> {
+        Self::open(DiskCache::data_dir()?.root().join(ANSWERS_FILENAME))
+    }
+
+    /// Returns the path of the answers file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the known-good answer of `part` of the puzzle of `year` and
+    /// `day`.
+    ///
+    /// # Arguments
+    ///
+    /// - `year` - The year of the puzzle.
+    /// - `day` - The day of the puzzle.
+    /// - `part` - The part of the puzzle, i. e. 1 or 2.
+    pub fn get(&self, year: u32, day: u32, part: u32) -> Option<&str> {
+        self.answers.get(&year.to_string())
+            .and_then(|days| days.get(&day.to_string()))
+            .and_then(|parts| parts.get(&part.to_string()))
+            .map(|answer| answer.as_str())
+    }
+
+    /// Sets the known-good answer of `part` of the puzzle of `year` and `day`.
+    /// The answer is not written to the file before [`AnswerStore::save`] is
+    /// called.
+    ///
+    /// # Arguments
+    ///
+    /// - `year` - The year of the puzzle.
+    /// - `day` - The day of the puzzle.
+    /// - `part` - The part of the puzzle, i. e. 1 or 2.
+    /// - `answer` - The answer.
+    pub fn set(&mut self, year: u32, day: u32, part: u32, answer: &str) {
+        self.answers.entry(year.to_string())
+            .or_default()
+            .entry(day.to_string())
+            .or_default()
+            .insert(part.to_string(), answer.to_string());
+    }
+
+    /// Checks `answer` against the known-good answer of `part` of the puzzle
+    /// of `year` and `day`.
+    ///
+    /// # Arguments
+    ///
+    /// - `year` - The year of the puzzle.
+    /// - `day` - The day of the puzzle.
+    /// - `part` - The part of the puzzle, i. e. 1 or 2.
+    /// - `answer` - The answer to check.
+    pub fn verify(&self, year: u32, day: u32, part: u32, answer: &str) -> Verification {
+        match self.get(year, day, part) {
+            Some(expected) if expected == answer => Verification::Match,
+            Some(expected) => Verification::Mismatch(expected.to_string()),
+            None => Verification::Unknown,
+        }
+    }
+
+    /// Writes all answers to the answers file.
+    pub fn save(&self) -> Result<(), AnswerStoreError> {
+        let toml = toml::to_string(&self.answers)
+            .map_err(|e| AnswerStoreError::WriteAnswers(e, format!("{:?}", self.path)))?;
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| AnswerStoreError::AccessAnswers(e, format!("{:?}", parent)))?;
+        }
+
+        std::fs::write(&self.path, toml)
+            .map_err(|e| AnswerStoreError::AccessAnswers(e, format!("{:?}", self.path)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp_dir::TempDir;
+
+    #[test]
+    fn open_should_return_empty_store_if_file_does_not_exist() {
+        let dir = TempDir::new("answers-missing");
+        let store = AnswerStore::open(dir.join(ANSWERS_FILENAME)).unwrap();
+
+        assert_eq!(None, store.get(2024, 4, 1));
+    }
+
+    #[test]
+    fn save_should_write_answers_that_can_be_opened_again() {
+        let dir = TempDir::new("answers-save");
+        let path = dir.join(ANSWERS_FILENAME);
+        let mut store = AnswerStore::open(&path).unwrap();
+
+        store.set(2024, 4, 1, "2406");
+        store.set(2024, 4, 2, "1807");
+        store.set(2024, 10, 1, "12");
+        store.save().unwrap();
+
+        let store = AnswerStore::open(&path).unwrap();
+
+        assert_eq!(Some("2406"), store.get(2024, 4, 1));
+        assert_eq!(Some("1807"), store.get(2024, 4, 2));
+        assert_eq!(Some("12"), store.get(2024, 10, 1));
+    }
+
+    #[test]
+    fn open_should_read_answers_by_year_and_day() {
+        let dir = TempDir::new("answers-read");
+        let path = dir.join(ANSWERS_FILENAME);
+
+        std::fs::write(&path, "[2024.6]\n1 = \"41\"\n2 = \"6\"\n").unwrap();
+
+        let store = AnswerStore::open(&path).unwrap();
+
+        assert_eq!(Some("41"), store.get(2024, 6, 1));
+        assert_eq!(Some("6"), store.get(2024, 6, 2));
+    }
+
+    #[test]
+    fn verify_should_compare_with_known_answer() {
+        let dir = TempDir::new("answers-verify");
+        let mut store = AnswerStore::open(dir.join(ANSWERS_FILENAME)).unwrap();
+
+        store.set(2024, 6, 1, "41");
+
+        assert_eq!(Verification::Match, store.verify(2024, 6, 1, "41"));
+        assert_eq!(Verification::Mismatch("41".to_string()), store.verify(2024, 6, 1, "42"));
+        assert_eq!(Verification::Unknown, store.verify(2024, 6, 2, "6"));
+    }
+}
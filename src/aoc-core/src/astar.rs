@@ -0,0 +1,249 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::primitives::Position;
+
+#[derive(Debug, Eq, PartialEq)]
+struct State<N, W> {
+    estimate: W,
+    cost: W,
+    node: N,
+}
+
+// implement Ord and PartialOrd for the priority queue
+impl<N: Eq, W: Ord> Ord for State<N, W> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // reverse order for min-heap, prefer nodes further along on ties
+        other.estimate.cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<N: Eq, W: Ord> PartialOrd for State<N, W> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Implements the A* search from `start` to the nearest node for which
+/// `is_goal` returns `true`. Returns the path and its cost or `None`, if no
+/// goal can be reached. The result is a shortest path as long as the
+/// `heuristic` never overestimates the remaining cost to a goal. With a
+/// heuristic that always returns zero, it is the same as
+/// [`dijkstra_to_any`](crate::dijkstra::dijkstra_to_any).
+///
+/// # Arguments
+///
+/// - `start` - The starting node.
+/// - `is_goal` - Returns `true` for the end nodes.
+/// - `neighbors` - Returns the neighbors of a node together with the weight
+///   of the edge.
+/// - `heuristic` - Returns the estimated remaining cost from a node to the
+///   nearest goal.
+pub fn astar<N, W, I>(
+    start: N,
+    is_goal: impl Fn(&N) -> bool,
+    neighbors: impl Fn(&N) -> I,
+    heuristic: impl Fn(&N) -> W,
+) -> Option<(Vec<N>, W)>
+where
+    N: Clone + Eq + Hash,
+    W: Copy + Ord + Add<Output = W> + Default,
+    I: IntoIterator<Item = (N, W)>,
+{
+    // min-heap for priority queue
+    let mut heap: BinaryHeap<State<N, W>> = BinaryHeap::new();
+    let mut costs: HashMap<N, W> = HashMap::new();
+    let mut previous: HashMap<N, N> = HashMap::new();
+
+    costs.insert(start.clone(), W::default());
+    heap.push(State { estimate: heuristic(&start), cost: W::default(), node: start });
+
+    while let Some(State { cost, node, .. }) = heap.pop() {
+        if is_goal(&node) {
+            let mut path = vec![node.clone()];
+            let mut current = &node;
+
+            while let Some(prev) = previous.get(current) {
+                path.push(prev.clone());
+                current = prev;
+            }
+
+            path.reverse();
+
+            return Some((path, cost));
+        }
+
+        // skip if we've already found a better way
+        if costs.get(&node).is_some_and(|&known| cost > known) {
+            continue;
+        }
+
+        for (next, weight) in neighbors(&node) {
+            let next_cost = cost + weight;
+
+            if costs.get(&next).is_none_or(|&known| next_cost < known) {
+                costs.insert(next.clone(), next_cost);
+                previous.insert(next.clone(), node.clone());
+                let estimate = next_cost + heuristic(&next);
+
+                heap.push(State { estimate, cost: next_cost, node: next });
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns a heuristic for [`astar`] that is the Manhattan distance to
+/// `goal`. It never overestimates, if every step between neighboring cells
+/// costs at least one and there are no diagonal steps.
+///
+/// # Arguments
+///
+/// - `goal` - The position to estimate the distance to.
+pub fn manhattan<W>(goal: Position) -> impl Fn(&Position) -> W
+where
+    W: TryFrom<usize> + Default,
+{
+    move |pos| W::try_from(pos.manhattan_distance(goal)).unwrap_or_default()
+}
+
+/// Returns a heuristic for [`astar`] that is the Chebyshev distance to
+/// `goal`. It never overestimates, if every step between neighboring cells
+/// costs at least one, including diagonal steps.
+///
+/// # Arguments
+///
+/// - `goal` - The position to estimate the distance to.
+pub fn chebyshev<W>(goal: Position) -> impl Fn(&Position) -> W
+where
+    W: TryFrom<usize> + Default,
+{
+    move |pos| W::try_from(pos.chebyshev_distance(goal)).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{
+        dijkstra::dijkstra,
+        graph::Graph,
+        grid::Grid,
+        grid_graph::GridGraph,
+        region::Connectivity,
+        text_map::TextMap,
+        undirected_graph::UndirectedGraph,
+    };
+
+    const MAZE: &str = r"S.#.....
+.##.###.
+....#...
+.####.#.
+......#E";
+
+    fn walkable(_: &char, to: &char) -> Option<i32> {
+        (*to != '#').then_some(1)
+    }
+
+    #[test]
+    fn astar_should_find_shortest_path_through_maze() {
+        let maze = TextMap::try_from(MAZE).unwrap();
+        let graph = GridGraph::new(&maze, Connectivity::Four, walkable);
+        let goal = Position::new(7, 4);
+
+        let (path, cost) = astar(
+            Position::new(0, 0),
+            |&pos| pos == goal,
+            |pos| graph.neighbors(pos),
+            manhattan(goal)).unwrap();
+
+        assert_eq!(15, cost);
+        assert_eq!(16, path.len());
+        assert_eq!(Some(&goal), path.last());
+    }
+
+    #[test]
+    fn astar_should_return_none_if_goal_is_walled_off() {
+        let maze = TextMap::try_from("S#.\n##.\n..E").unwrap();
+        let graph = GridGraph::new(&maze, Connectivity::Four, walkable);
+        let goal = Position::new(2, 2);
+
+        let result = astar(
+            Position::new(0, 0),
+            |&pos| pos == goal,
+            |pos| graph.neighbors(pos),
+            manhattan(goal));
+
+        assert_eq!(None, result);
+    }
+
+    #[test]
+    fn astar_should_use_diagonals_with_chebyshev_heuristic() {
+        let maze = TextMap::try_from(MAZE).unwrap();
+        let graph = GridGraph::new(&maze, Connectivity::Eight, walkable);
+        let goal = Position::new(7, 0);
+
+        let result = astar(
+            Position::new(0, 0),
+            |&pos| pos == goal,
+            |pos| graph.neighbors(pos),
+            chebyshev(goal));
+
+        assert_eq!(
+            dijkstra(&graph, &Position::new(0, 0), &goal).map(|(_, cost)| cost),
+            result.map(|(_, cost)| cost));
+    }
+
+    #[test]
+    fn astar_should_return_same_cost_as_dijkstra_on_graph() {
+        let mut graph = UndirectedGraph::new();
+
+        graph.add_edge("London", "Dublin", 464);
+        graph.add_edge("Dublin", "Paris", 500);
+        graph.add_edge("Dublin", "Berlin", 1200);
+        graph.add_edge("Paris", "Berlin", 600);
+        graph.add_edge("London", "Paris", 344);
+
+        let result = astar(
+            "London".to_string(),
+            |node| node == "Berlin",
+            |node| graph.neighbors(node),
+            |_| 0);
+
+        assert_eq!(dijkstra(&graph, "London", "Berlin"), result);
+    }
+
+    fn any_maze() -> impl Strategy<Value = Grid<u8>> {
+        (1..8usize, 1..8usize).prop_flat_map(|(width, height)| {
+            proptest::collection::vec(1..10u8, width * height)
+                .prop_map(move |data| Grid::from_vec(width, height, data))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn astar_should_find_same_cost_as_dijkstra(maze in any_maze()) {
+            // cells with 9 are walls, the others cost their value to enter
+            let graph = GridGraph::new(
+                &maze,
+                Connectivity::Four,
+                |_: &u8, &to: &u8| (to < 9).then_some(to as u32));
+            let start = Position::new(0, 0);
+            let goal = Position::new(maze.width() - 1, maze.height() - 1);
+
+            let result = astar(
+                start, |&pos| pos == goal, |pos| graph.neighbors(pos), manhattan(goal));
+
+            prop_assert_eq!(
+                dijkstra(&graph, &start, &goal).map(|(_, cost)| cost),
+                result.map(|(_, cost)| cost));
+        }
+    }
+}
@@ -0,0 +1,470 @@
+use std::{
+    io,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{
+    input_source::DiskCache,
+    solution::{solve_part, Solution, SolutionError},
+    InputError,
+};
+
+const BASELINE_FILENAME: &str = r"bench.json";
+
+/// Errors that can happen while storing or loading a benchmark baseline.
+#[derive(Error, Debug)]
+pub enum BenchError {
+    /// There was an error with the data directory.
+    #[error(transparent)]
+    Input(#[from] InputError),
+
+    /// There was an I/O error while reading or writing the baseline file.
+    #[error("There was an I/O error while accessing the baseline file '{1}'.")]
+    AccessBaseline(#[source] io::Error, String),
+
+    /// The baseline file could not be parsed.
+    #[error("The baseline file '{1}' is invalid.")]
+    InvalidBaseline(#[source] serde_json::Error, String),
+}
+
+/// The options for benchmarking a solution.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct BenchOptions {
+    /// The number of measured runs of every stage. At least one run is
+    /// measured, even if it is 0.
+    pub runs: usize,
+
+    /// The number of runs of every stage before the measurement starts.
+    pub warmup: usize,
+}
+
+impl BenchOptions {
+    // the number of runs that are actually measured
+    fn measured_runs(&self) -> usize {
+        self.runs.max(1)
+    }
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self { runs: 10, warmup: 2 }
+    }
+}
+
+/// The statistics of the measured run times of a stage.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    /// The fastest run.
+    pub min: Duration,
+
+    /// The median of the runs.
+    pub median: Duration,
+
+    /// The mean of the runs.
+    pub mean: Duration,
+
+    /// The standard deviation of the runs.
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Calculates the statistics of the given run times.
+    ///
+    /// # Arguments
+    ///
+    /// - `samples` - The measured run times. There must be at least one.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "At least one sample is needed for the statistics!");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let nanos: Vec<f64> = sorted.iter().map(|sample| sample.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+/// The statistics of a stage of a solution, i. e. of parsing, of a part or
+/// of a variant of a part.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StageReport {
+    /// The name of the stage, e. g. `parse`, `part 1` or `part 1 (recursive)`.
+    pub name: String,
+
+    /// The statistics of the run times.
+    pub stats: Stats,
+}
+
+/// A stage that became slower compared to the baseline.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Regression {
+    /// The name of the stage.
+    pub stage: String,
+
+    /// The median run time of the baseline.
+    pub baseline: Duration,
+
+    /// The current median run time.
+    pub current: Duration,
+}
+
+impl Regression {
+    /// Returns the relative change of the median run time, e. g. `0.25` if the
+    /// stage became 25% slower.
+    pub fn change(&self) -> f64 {
+        relative_change(self.baseline, self.current)
+    }
+}
+
+/// The result of benchmarking a solution.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BenchReport {
+    /// The year of the puzzle.
+    pub year: u32,
+
+    /// The day of the puzzle.
+    pub day: u32,
+
+    /// The number of measured runs of every stage.
+    pub runs: usize,
+
+    /// The number of warmup runs of every stage.
+    pub warmup: usize,
+
+    /// The statistics of all stages.
+    pub stages: Vec<StageReport>,
+}
+
+impl BenchReport {
+    /// Returns the report of the stage with the given `name`.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the stage.
+    pub fn stage(&self, name: &str) -> Option<&StageReport> {
+        self.stages.iter().find(|stage| stage.name == name)
+    }
+
+    /// Returns the relative change of the median run time of every stage
+    /// compared to the `baseline`. Stages that are not in the baseline are
+    /// `None`.
+    ///
+    /// # Arguments
+    ///
+    /// - `baseline` - The report to compare with.
+    pub fn changes(&self, baseline: &BenchReport) -> Vec<(String, Option<f64>)> {
+        self.stages.iter()
+            .map(|stage| {
+                let change = baseline.stage(&stage.name)
+                    .map(|base| relative_change(base.stats.median, stage.stats.median));
+
+                (stage.name.clone(), change)
+            })
+            .collect()
+    }
+
+    /// Returns all stages whose median run time is more than `threshold`
+    /// slower than in the `baseline`.
+    ///
+    /// # Arguments
+    ///
+    /// - `baseline` - The report to compare with.
+    /// - `threshold` - The relative change that is tolerated, e. g. `0.1` for 10%.
+    pub fn regressions(&self, baseline: &BenchReport, threshold: f64) -> Vec<Regression> {
+        self.stages.iter()
+            .filter_map(|stage| {
+                let base = baseline.stage(&stage.name)?;
+                let regression = Regression {
+                    stage: stage.name.clone(),
+                    baseline: base.stats.median,
+                    current: stage.stats.median,
+                };
+
+                (regression.change() > threshold).then_some(regression)
+            })
+            .collect()
+    }
+}
+
+/// Benchmarks parsing, both parts and all variants of a solution. Returns an
+/// error, if the puzzle input is invalid.
+///
+/// # Arguments
+///
+/// - `solution` - The solution to benchmark.
+/// - `puzzle_input` - The puzzle input.
+/// - `options` - The number of runs and warmup runs.
+pub fn bench<S: Solution>(solution: &S, puzzle_input: &str, options: &BenchOptions)
+    -> Result<BenchReport, SolutionError>
+{
+    let input = solution.parse(puzzle_input)?;
+    let parse_stats = measure(options, || solution.parse(puzzle_input));
+
+    let mut stages = vec![StageReport { name: "parse".to_string(), stats: parse_stats }];
+
+    for part in [1, 2] {
+        stages.push(StageReport {
+            name: format!("part {}", part),
+            stats: measure(options, || solve_part(solution, &input, part)),
+        });
+    }
+
+    for variant in solution.variants() {
+        stages.push(StageReport {
+            name: format!("part {} ({})", variant.part, variant.name),
+            stats: measure(options, || (variant.solve)(solution, &input)),
+        });
+    }
+
+    Ok(BenchReport {
+        year: S::YEAR,
+        day: S::DAY,
+        runs: options.measured_runs(),
+        warmup: options.warmup,
+        stages,
+    })
+}
+
+/// Runs `f` for the warmup and then measures `options.runs` runs.
+fn measure<T>(options: &BenchOptions, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..options.warmup {
+        std::hint::black_box(f());
+    }
+
+    let samples: Vec<Duration> = (0..options.measured_runs())
+        .map(|_| {
+            let start = Instant::now();
+            std::hint::black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(&samples)
+}
+
+fn relative_change(baseline: Duration, current: Duration) -> f64 {
+    let baseline = baseline.as_nanos() as f64;
+    let current = current.as_nanos() as f64;
+
+    if baseline == 0.0 {
+        return 0.0;
+    }
+
+    (current - baseline) / baseline
+}
+
+/// Stores the benchmark baselines in the on-disk cache next to the input of
+/// the puzzle as `bench.json`.
+#[derive(Debug, Clone)]
+pub struct BaselineStore {
+    cache: DiskCache,
+}
+
+impl BaselineStore {
+    /// Creates a new `BaselineStore`.
+    ///
+    /// # Arguments
+    ///
+    /// - `cache` - The cache to store the baselines in.
+    pub fn new(cache: DiskCache) -> Self {
+        Self { cache }
+    }
+
+    /// Creates a new `BaselineStore` that uses the data directory of the user.
+    pub fn data_dir() -> Result<Self, BenchError> {
+        Ok(Self::new(DiskCache::data_dir()?))
+    }
+
+    /// Returns the baseline of the puzzle of `year` and `day`, if one was
+    /// saved before.
+    ///
+    /// # Arguments
+    ///
+    /// - `year` - The year of the puzzle.
+    /// - `day` - The day of the puzzle.
+    pub fn load(&self, year: u32, day: u32) -> Result<Option<BenchReport>, BenchError> {
+        let baseline_file = self.baseline_file(year, day);
+
+        if !baseline_file.exists() {
+            return Ok(None);
+        }
+
+        let json = std::fs::read_to_string(&baseline_file)
+            .map_err(|e| BenchError::AccessBaseline(e, format!("{:?}", baseline_file)))?;
+
+        serde_json::from_str(&json)
+            .map(Some)
+            .map_err(|e| BenchError::InvalidBaseline(e, format!("{:?}", baseline_file)))
+    }
+
+    /// Saves `report` as the baseline of its puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// - `report` - The report to save.
+    pub fn save(&self, report: &BenchReport) -> Result<(), BenchError> {
+        self.cache.provision_puzzle_dir(report.year, report.day)?;
+
+        let baseline_file = self.baseline_file(report.year, report.day);
+        let json = serde_json::to_string_pretty(report)
+            .map_err(|e| BenchError::InvalidBaseline(e, format!("{:?}", baseline_file)))?;
+
+        std::fs::write(&baseline_file, json)
+            .map_err(|e| BenchError::AccessBaseline(e, format!("{:?}", baseline_file)))
+    }
+
+    fn baseline_file(&self, year: u32, day: u32) -> PathBuf {
+        self.cache.puzzle_dir(year, day).join(BASELINE_FILENAME)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{solution::Variant, temp_dir::TempDir};
+
+    fn millis(ms: &[u64]) -> Vec<Duration> {
+        ms.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    fn report(stages: &[(&str, u64)]) -> BenchReport {
+        BenchReport {
+            year: 2024,
+            day: 7,
+            runs: 1,
+            warmup: 0,
+            stages: stages.iter()
+                .map(|&(name, ms)| StageReport {
+                    name: name.to_string(),
+                    stats: Stats::from_samples(&millis(&[ms])),
+                })
+                .collect(),
+        }
+    }
+
+    struct Count;
+
+    impl Solution for Count {
+        const YEAR: u32 = 2024;
+        const DAY: u32 = 0;
+
+        type Input = Vec<u32>;
+
+        fn parse(&self, puzzle_input: &str) -> Result<Self::Input, SolutionError> {
+            puzzle_input.split(',')
+                .map(|n| n.parse().map_err(|_| SolutionError::InvalidInput(n.to_string())))
+                .collect()
+        }
+
+        fn part1(&self, input: &Self::Input) -> String {
+            input.len().to_string()
+        }
+
+        fn part2(&self, input: &Self::Input) -> String {
+            input.iter().sum::<u32>().to_string()
+        }
+
+        fn variants(&self) -> Vec<Variant<Self>> {
+            vec![Variant {
+                name: "reversed",
+                part: 2,
+                solve: |_, input| input.iter().rev().sum::<u32>().to_string(),
+            }]
+        }
+    }
+
+    #[test]
+    fn from_samples_should_calculate_statistics() {
+        let stats = Stats::from_samples(&millis(&[4, 2, 6, 8]));
+
+        assert_eq!(Duration::from_millis(2), stats.min);
+        assert_eq!(Duration::from_millis(5), stats.median);
+        assert_eq!(Duration::from_millis(5), stats.mean);
+        assert_eq!(Duration::from_nanos(2_236_068), stats.stddev);
+    }
+
+    #[test]
+    fn from_samples_should_take_middle_sample_as_median_for_odd_count() {
+        assert_eq!(
+            Duration::from_millis(3),
+            Stats::from_samples(&millis(&[9, 1, 3])).median);
+    }
+
+    #[test]
+    fn bench_should_measure_parse_parts_and_variants() {
+        let report = bench(&Count, "1,2,3", &BenchOptions { runs: 3, warmup: 1 }).unwrap();
+
+        assert_eq!(
+            vec!["parse", "part 1", "part 2", "part 2 (reversed)"],
+            report.stages.iter().map(|stage| stage.name.as_str()).collect::<Vec<_>>());
+        assert_eq!((2024, 0, 3, 1), (report.year, report.day, report.runs, report.warmup));
+    }
+
+    #[test]
+    fn bench_should_record_one_run_when_zero_runs_are_requested() {
+        let report = bench(&Count, "1,2,3", &BenchOptions { runs: 0, warmup: 0 }).unwrap();
+
+        assert_eq!(1, report.runs);
+    }
+
+    #[test]
+    fn bench_should_fail_for_invalid_input() {
+        assert!(bench(&Count, "1,x", &BenchOptions::default()).is_err());
+    }
+
+    #[test]
+    fn regressions_should_flag_stages_slower_than_threshold() {
+        let baseline = report(&[("parse", 10), ("part 1", 100), ("part 2", 100)]);
+        let current = report(&[("parse", 11), ("part 1", 150), ("part 2", 80), ("part 2 (reversed)", 500)]);
+
+        let regressions = current.regressions(&baseline, 0.2);
+
+        assert_eq!(1, regressions.len());
+        assert_eq!("part 1", regressions[0].stage);
+        assert!((regressions[0].change() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn changes_should_be_none_for_stages_missing_in_baseline() {
+        let baseline = report(&[("part 1", 100)]);
+        let current = report(&[("part 1", 90), ("part 2", 80)]);
+
+        let changes = current.changes(&baseline);
+
+        assert_eq!("part 1", changes[0].0);
+        assert!((changes[0].1.unwrap() + 0.1).abs() < 1e-9);
+        assert_eq!(("part 2".to_string(), None), changes[1]);
+    }
+
+    #[test]
+    fn baseline_store_should_save_and_load_report() {
+        let dir = TempDir::new("bench-baseline");
+        let store = BaselineStore::new(DiskCache::new(dir.path()));
+        let report = report(&[("parse", 10), ("part 1", 100)]);
+
+        assert_eq!(None, store.load(2024, 7).unwrap());
+
+        store.save(&report).unwrap();
+
+        assert_eq!(Some(report), store.load(2024, 7).unwrap());
+    }
+}
@@ -0,0 +1,141 @@
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::graph::Graph;
+
+/// Finds the path from `start` to `goal` with the fewest edges by using a
+/// breadth-first search. The weights of the edges are ignored. Returns `None`
+/// if the goal is not reachable.
+///
+/// # Arguments
+///
+/// - `graph` - the graph
+/// - `start` - the starting node
+/// - `goal` - the end node
+pub fn bfs<G, Q>(graph: &G, start: &Q, goal: &Q) -> Option<Vec<G::Node>>
+where
+    G: Graph,
+    G::Node: Borrow<Q>,
+    Q: ToOwned<Owned = G::Node> + Eq + Hash + ?Sized,
+{
+    let start = start.to_owned();
+    let mut previous: HashMap<G::Node, G::Node> = HashMap::new();
+    let mut visited: HashSet<G::Node> = HashSet::from([start.clone()]);
+    let mut queue: VecDeque<G::Node> = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if node.borrow() == goal {
+            let mut path = vec![node];
+
+            while let Some(prev) = previous.get::<G::Node>(&path[path.len() - 1]) {
+                path.push(prev.clone());
+            }
+
+            path.reverse();
+
+            return Some(path);
+        }
+
+        for (next, _) in graph.neighbors(&node) {
+            if visited.insert(next.clone()) {
+                previous.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns the nodes that are reachable from `start` grouped by the number
+/// of edges needed to reach them, i. e. the first level only contains
+/// `start`, the second level its neighbors and so on. The weights of the
+/// edges are ignored.
+///
+/// # Arguments
+///
+/// - `graph` - the graph
+/// - `start` - the starting node
+pub fn bfs_levels<G, Q>(graph: &G, start: &Q) -> Vec<Vec<G::Node>>
+where
+    G: Graph,
+    G::Node: Borrow<Q>,
+    Q: ToOwned<Owned = G::Node> + ?Sized,
+{
+    let start = start.to_owned();
+    let mut visited: HashSet<G::Node> = HashSet::from([start.clone()]);
+    let mut levels: Vec<Vec<G::Node>> = vec![];
+    let mut level = vec![start];
+
+    while !level.is_empty() {
+        let mut next_level = vec![];
+
+        for node in &level {
+            for (next, _) in graph.neighbors(node) {
+                if visited.insert(next.clone()) {
+                    next_level.push(next);
+                }
+            }
+        }
+
+        levels.push(level);
+        level = next_level;
+    }
+
+    levels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{directed_graph::DirectedGraph, undirected_graph::UndirectedGraph};
+
+    fn graph() -> UndirectedGraph {
+        let mut graph = UndirectedGraph::new();
+
+        graph.add_edge("London", "Dublin", 464);
+        graph.add_edge("Dublin", "Paris", 500);
+        graph.add_edge("Paris", "Berlin", 600);
+        graph.add_edge("London", "Berlin", 5000);
+        graph.add_node("Rome");
+
+        graph
+    }
+
+    #[test]
+    fn bfs_should_return_path_with_fewest_edges() {
+        assert_eq!(
+            bfs(&graph(), "London", "Berlin"),
+            Some(vec!["London".to_string(), "Berlin".to_string()]));
+    }
+
+    #[test]
+    fn bfs_should_return_none_if_goal_is_not_reachable() {
+        assert_eq!(bfs(&graph(), "London", "Rome"), None);
+    }
+
+    #[test]
+    fn bfs_levels_should_group_nodes_by_number_of_edges() {
+        assert_eq!(
+            bfs_levels(&graph(), "Dublin"),
+            vec![
+                vec!["Dublin".to_string()],
+                vec!["London".to_string(), "Paris".to_string()],
+                vec!["Berlin".to_string()],
+            ]);
+    }
+
+    #[test]
+    fn bfs_should_follow_edge_direction_in_directed_graph() {
+        let mut graph = DirectedGraph::new();
+
+        graph.add_edge("A", "B", 1);
+        graph.add_edge("B", "C", 1);
+
+        assert_eq!(bfs(&graph, "C", "A"), None);
+        assert_eq!(bfs_levels(&graph, "B"), vec![vec!["B".to_string()], vec!["C".to_string()]]);
+    }
+}
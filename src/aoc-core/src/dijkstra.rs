@@ -1,96 +1,310 @@
-use std::{cmp::Ordering, collections::{BinaryHeap, HashMap}};
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+    hash::Hash,
+};
 
-use crate::undirected_graph::UndirectedGraph;
+use crate::graph::Graph;
 
 #[derive(Debug, Eq, PartialEq)]
-struct State {
-    cost: i32,
-    position: String,
+struct State<N, W> {
+    cost: W,
+    node: N,
 }
 
 // implement Ord and PartialOrd for the priority queue
-impl Ord for State {
+impl<N: Eq, W: Ord> Ord for State<N, W> {
     fn cmp(&self, other: &Self) -> Ordering {
         // reverse order for min-heap
         other.cost.cmp(&self.cost)
     }
 }
 
-impl PartialOrd for State {
+impl<N: Eq, W: Ord> PartialOrd for State<N, W> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-/// Implements Dijkstra's algorithm.
-/// Created by ChatGPT: https://chatgpt.com/c/67496bd6-74b0-8008-9b71-6871ce1613e5
-/// 
-/// # Arguments
-/// 
-/// - `graph` - the undirected graph
-/// - `start` - the starting node
-/// - `goal` - the end node
-pub fn dijkstra(graph: &UndirectedGraph, start: &str, goal: &str)
-    -> Option<(Vec<String>, i32)>
+/// The result of a search from a start node: the distances of all reached
+/// nodes and, for every node, all predecessors on a shortest path, i. e. the
+/// predecessor graph of all tied shortest paths. Edges with zero weight
+/// between nodes with the same distance can make the predecessor graph
+/// cyclic.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<N, W> {
+    start: N,
+    distances: HashMap<N, W>,
+    predecessors: HashMap<N, Vec<N>>,
+}
+
+impl<N, W> ShortestPaths<N, W>
+where
+    N: Clone + Eq + Hash,
+    W: Copy,
 {
-    // min-heap for priority queue
-    let mut heap: BinaryHeap<State> = BinaryHeap::new();
-    let mut distances: HashMap<String, i32> = HashMap::new();
-    let mut predecessors: HashMap<String, String> = HashMap::new();
+    /// Returns the start node of the search.
+    pub fn start(&self) -> &N {
+        &self.start
+    }
 
-    // initialize distances to all nodes as infinity, except the start node
-    for node in graph.get_nodes().iter() {
-        distances.insert(node.clone(), i32::MAX);
+    /// Returns the distances of all reached nodes.
+    pub fn distances(&self) -> &HashMap<N, W> {
+        &self.distances
     }
-    distances.insert(start.to_string(), 0);
-    
-    // push the start node into the heap
-    heap.push(State { cost: 0, position: start.to_string() });
 
-    while let Some(State { cost, position }) = heap.pop() {
-        // if we reached the goal, construct the path
-        if position == goal {
-            let mut path = vec![];
-            let mut current = goal.to_string();
+    /// Returns the distance of `node` or `None`, if it was not reached.
+    ///
+    /// # Arguments
+    ///
+    /// - `node` - The node to get the distance of.
+    pub fn distance<Q>(&self, node: &Q) -> Option<W>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.distances.get(node).copied()
+    }
 
-            while let Some(pred) = predecessors.get(&current) {
-                path.push(current);
-                current = pred.clone();
-            }
+    /// Returns all predecessors of `node` on a shortest path from the start.
+    ///
+    /// # Arguments
+    ///
+    /// - `node` - The node to get the predecessors of.
+    pub fn predecessors<Q>(&self, node: &Q) -> &[N]
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.predecessors.get(node).map_or(&[], |predecessors| predecessors.as_slice())
+    }
+
+    /// Returns a shortest path from the start to `node` or `None`, if it was
+    /// not reached. If there are several, the first one found is returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `node` - The node to get the path to.
+    pub fn path_to<Q>(&self, node: &Q) -> Option<Vec<N>>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (mut current, _) = self.distances.get_key_value(node)?;
+        let mut path = vec![current.clone()];
 
-            path.push(start.to_string());
-            path.reverse();
+        while let Some(pred) = self.predecessors(current.borrow()).first() {
+            path.push(pred.clone());
+            current = pred;
+        }
+
+        path.reverse();
+
+        Some(path)
+    }
 
-            return Some((path, cost));
+    /// Returns all shortest paths from the start to `node`. The result is
+    /// empty, if `node` was not reached.
+    ///
+    /// # Arguments
+    ///
+    /// - `node` - The node to get the paths to.
+    pub fn all_paths_to<Q>(&self, node: &Q) -> Vec<Vec<N>>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let Some((node, _)) = self.distances.get_key_value(node) else {
+            return vec![];
+        };
+
+        let mut paths: Vec<Vec<N>> = vec![];
+        let mut stack: Vec<Vec<N>> = vec![vec![node.clone()]];
+
+        // walk backwards through the predecessor graph, skipping nodes that
+        // are already on the path to not run around zero weight cycles
+        while let Some(mut path) = stack.pop() {
+            let last = &path[path.len() - 1];
+
+            if *last == self.start {
+                path.reverse();
+                paths.push(path);
+                continue;
+            }
+
+            for pred in self.predecessors(last.borrow()) {
+                if !path.contains(pred) {
+                    let mut longer = path.clone();
+                    longer.push(pred.clone());
+                    stack.push(longer);
+                }
+            }
         }
 
+        paths
+    }
+}
+
+/// All shortest paths to a node together with their cost.
+pub type AllPaths<N, W> = (Vec<Vec<N>>, W);
+
+// the shortest paths found and the node the search stopped at
+type SearchResult<N, W> = (ShortestPaths<N, W>, Option<N>);
+
+/// Runs Dijkstra's algorithm from `start` until `stop` returns `true` for a
+/// settled node and its distance, or until all reachable nodes are settled.
+fn search<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut stop: impl FnMut(&G::Node, G::Weight) -> bool,
+) -> SearchResult<G::Node, G::Weight> {
+    // min-heap for priority queue
+    let mut heap: BinaryHeap<State<G::Node, G::Weight>> = BinaryHeap::new();
+    let mut distances: HashMap<G::Node, G::Weight> = HashMap::new();
+    let mut predecessors: HashMap<G::Node, Vec<G::Node>> = HashMap::new();
+
+    // nodes without a distance are not reached yet, i. e. their distance is
+    // infinity
+    distances.insert(start.clone(), G::Weight::default());
+
+    // push the start node into the heap
+    heap.push(State { cost: G::Weight::default(), node: start.clone() });
+
+    let mut stopped_at = None;
+
+    while let Some(State { cost, node }) = heap.pop() {
         // skip if we've already found a better way
-        if cost > *distances.get(&position).unwrap() {
+        if distances.get(&node).is_some_and(|&distance| cost > distance) {
             continue;
         }
 
+        if stop(&node, cost) {
+            stopped_at = Some(node);
+            break;
+        }
+
         // explore neighbors
-        for (to, weight_to) in graph.get_edges(&position) {
-            let next = State {
-                cost: cost + weight_to,
-                position: to.clone(),
-            };
-
-            if next.cost < *distances.get(&to).unwrap_or(&i32::MAX) {
-                distances.insert(to.clone(), next.cost);
-                predecessors.insert(to, position.clone());
-                heap.push(next);
+        for (to, weight_to) in graph.neighbors(&node) {
+            let next_cost = cost + weight_to;
+
+            match distances.get(&to) {
+                Some(&distance) if next_cost > distance => {}
+                Some(&distance) if next_cost == distance => {
+                    // another shortest path with the same cost
+                    let preds = predecessors.entry(to).or_default();
+
+                    if !preds.contains(&node) {
+                        preds.push(node.clone());
+                    }
+                }
+                _ => {
+                    distances.insert(to.clone(), next_cost);
+                    predecessors.insert(to.clone(), vec![node.clone()]);
+                    heap.push(State { cost: next_cost, node: to });
+                }
             }
         }
     }
 
-    // if the goal is not reachable, return None
-    None
+    // the start node can be reached again over edges with zero weight
+    predecessors.remove(&start);
+
+    (ShortestPaths { start, distances, predecessors }, stopped_at)
+}
+
+/// Implements Dijkstra's algorithm on any [`Graph`], e. g. an
+/// [`UndirectedGraph`](crate::undirected_graph::UndirectedGraph) or a
+/// [`GridGraph`](crate::grid_graph::GridGraph). The nodes can be given in
+/// their borrowed form, e. g. `&str` for `String` nodes.
+/// Created by ChatGPT: https://chatgpt.com/c/67496bd6-74b0-8008-9b71-6871ce1613e5
+/// 
+/// # Arguments
+/// 
+/// - `graph` - the graph
+/// - `start` - the starting node
+/// - `goal` - the end node
+pub fn dijkstra<G, Q>(graph: &G, start: &Q, goal: &Q) -> Option<(Vec<G::Node>, G::Weight)>
+where
+    G: Graph,
+    G::Node: Borrow<Q>,
+    Q: ToOwned<Owned = G::Node> + Eq + Hash + ?Sized,
+{
+    dijkstra_to_any(graph, start, |node| node.borrow() == goal)
+}
+
+/// Finds the shortest path from `start` to the nearest node for which
+/// `is_goal` returns `true`. The search stops at the first goal that is
+/// reached.
+/// 
+/// # Arguments
+/// 
+/// - `graph` - the graph
+/// - `start` - the starting node
+/// - `is_goal` - returns `true` for the end nodes
+pub fn dijkstra_to_any<G, Q>(graph: &G, start: &Q, is_goal: impl Fn(&G::Node) -> bool)
+    -> Option<(Vec<G::Node>, G::Weight)>
+where
+    G: Graph,
+    G::Node: Borrow<Q>,
+    Q: ToOwned<Owned = G::Node> + ?Sized,
+{
+    let (paths, goal) = search(graph, start.to_owned(), |node, _| is_goal(node));
+    let goal = goal?;
+
+    Some((paths.path_to::<G::Node>(&goal)?, paths.distance::<G::Node>(&goal)?))
+}
+
+/// Runs Dijkstra's algorithm from `start` to all reachable nodes and returns
+/// their distances together with the predecessor DAG of all shortest paths.
+/// 
+/// # Arguments
+/// 
+/// - `graph` - the graph
+/// - `start` - the starting node
+pub fn dijkstra_all<G, Q>(graph: &G, start: &Q) -> ShortestPaths<G::Node, G::Weight>
+where
+    G: Graph,
+    G::Node: Borrow<Q>,
+    Q: ToOwned<Owned = G::Node> + ?Sized,
+{
+    search(graph, start.to_owned(), |_, _| false).0
+}
+
+/// Finds all shortest paths from `start` to `goal` that have the same,
+/// minimal cost. Returns `None` if the goal is not reachable.
+/// 
+/// # Arguments
+/// 
+/// - `graph` - the graph
+/// - `start` - the starting node
+/// - `goal` - the end node
+pub fn dijkstra_all_paths<G, Q>(graph: &G, start: &Q, goal: &Q)
+    -> Option<AllPaths<G::Node, G::Weight>>
+where
+    G: Graph,
+    G::Node: Borrow<Q>,
+    Q: ToOwned<Owned = G::Node> + Eq + Hash + ?Sized,
+{
+    let mut goal_cost = None;
+
+    // all predecessors of the goal are settled, once a node with a higher
+    // cost is settled
+    let (paths, _) = search(graph, start.to_owned(), |node, cost| {
+        if goal_cost.is_none() && node.borrow() == goal {
+            goal_cost = Some(cost);
+        }
+
+        goal_cost.is_some_and(|goal_cost| cost > goal_cost)
+    });
+
+    Some((paths.all_paths_to(goal), goal_cost?))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{directed_graph::DirectedGraph, undirected_graph::UndirectedGraph};
 
     #[test]
     fn dijkstra_should_return_shortest_path_for_single_node() {
@@ -156,4 +370,95 @@ mod tests {
                 "Berlin".to_string()],
             1564)));
     }
+
+    fn diamond() -> UndirectedGraph {
+        let mut graph = UndirectedGraph::new();
+
+        graph.add_edge("A", "B", 1);
+        graph.add_edge("A", "C", 1);
+        graph.add_edge("B", "D", 1);
+        graph.add_edge("C", "D", 1);
+        graph.add_edge("D", "E", 5);
+
+        graph
+    }
+
+    #[test]
+    fn dijkstra_should_return_none_if_goal_is_not_reachable() {
+        let mut graph = UndirectedGraph::new();
+
+        graph.add_node("London");
+        graph.add_node("Dublin");
+
+        assert_eq!(dijkstra(&graph, "London", "Dublin"), None);
+    }
+
+    #[test]
+    fn dijkstra_should_follow_edge_direction_in_directed_graph() {
+        let mut graph = DirectedGraph::new();
+
+        graph.add_edge("A", "B", 1);
+        graph.add_edge("B", "C", 1);
+        graph.add_edge("C", "A", 1);
+
+        assert_eq!(
+            dijkstra(&graph, "A", "C"),
+            Some((vec!["A".to_string(), "B".to_string(), "C".to_string()], 2)));
+        assert_eq!(
+            dijkstra(&graph, "C", "B"),
+            Some((vec!["C".to_string(), "A".to_string(), "B".to_string()], 2)));
+    }
+
+    #[test]
+    fn dijkstra_all_should_return_distances_and_predecessors() {
+        let paths = dijkstra_all(&diamond(), "A");
+
+        assert_eq!(5, paths.distances().len());
+        assert_eq!(Some(2), paths.distance("D"));
+        assert_eq!(Some(7), paths.distance("E"));
+        assert_eq!(2, paths.predecessors("D").len());
+        assert_eq!(Some(3), paths.path_to("D").map(|path| path.len()));
+        assert_eq!(None, paths.path_to("Z"));
+    }
+
+    #[test]
+    fn dijkstra_to_any_should_stop_at_nearest_goal() {
+        let graph = diamond();
+
+        assert_eq!(
+            dijkstra_to_any(&graph, "E", |node| node == "A" || node == "D"),
+            Some((vec!["E".to_string(), "D".to_string()], 5)));
+    }
+
+    #[test]
+    fn dijkstra_all_paths_should_return_all_tied_paths() {
+        let (mut paths, cost) = dijkstra_all_paths(&diamond(), "A", "E").unwrap();
+
+        paths.sort();
+
+        assert_eq!(7, cost);
+        assert_eq!(
+            vec![
+                vec!["A", "B", "D", "E"],
+                vec!["A", "C", "D", "E"],
+            ],
+            paths);
+    }
+
+    #[test]
+    fn dijkstra_all_paths_should_terminate_on_zero_weight_ties() {
+        let mut graph = UndirectedGraph::new();
+
+        graph.add_edge("S", "A", 1);
+        graph.add_edge("S", "B", 1);
+        graph.add_edge("A", "B", 0);
+
+        let (mut paths, cost) = dijkstra_all_paths(&graph, "S", "A").unwrap();
+
+        paths.sort();
+
+        assert_eq!(1, cost);
+        assert_eq!(vec![vec!["S", "A"], vec!["S", "B", "A"]], paths);
+        assert_eq!(Some(2), dijkstra_all(&graph, "S").path_to("B").map(|path| path.len()));
+    }
 }
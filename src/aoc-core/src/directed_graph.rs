@@ -1,19 +1,38 @@
-use std::collections::HashMap;
+use crate::indexed_graph::{EdgePolicy, IndexedGraph};
 
-/// A directed graph implementation.
+/// A directed graph implementation. It is a wrapper around an
+/// [`IndexedGraph`] with `String` nodes and `i32` weights.
 #[derive(Debug)]
 pub struct DirectedGraph {
-    adjacency_list: HashMap<String, Vec<(String, i32)>>,
+    graph: IndexedGraph<String, i32>,
+}
+
+impl Default for DirectedGraph {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl DirectedGraph {
     /// Creates a new graph.
     pub fn new() -> Self {
         Self {
-            adjacency_list: HashMap::new(),
+            graph: IndexedGraph::directed().with_policy(EdgePolicy::Multi),
         }
     }
 
+    /// Sets the policy for adding an edge between two nodes that are already
+    /// connected. The default is [`EdgePolicy::Multi`], i. e. parallel edges
+    /// are kept.
+    ///
+    /// # Arguments
+    ///
+    /// - `policy` - The policy for adding edges.
+    pub fn with_policy(mut self, policy: EdgePolicy) -> Self {
+        self.graph = self.graph.with_policy(policy);
+        self
+    }
+
     /// Adds a node to the graph.
     /// Will not add the node if it already exists and will not throw an error.
     /// 
@@ -21,11 +40,13 @@ impl DirectedGraph {
     /// 
     /// - `node` - The node to add to the graph.
     pub fn add_node(&mut self, node: &str) {
-        self.adjacency_list.entry(node.to_string()).or_insert(vec![]);
+        if self.graph.index_of(node).is_none() {
+            self.graph.add_node(node.to_string());
+        }
     }
 
-    /// Adds an edge to the graph.
-    /// Will not add the edge if it already exists and will not throw an error.
+    /// Adds an edge to the graph. If there already is an edge from `from` to
+    /// `to`, the [`EdgePolicy`] of the graph decides about the weight.
     /// 
     /// # Arguments
     /// 
@@ -33,18 +54,129 @@ impl DirectedGraph {
     /// - `to` - The node the edge is to.
     /// - `weight` - The weight of the edge.
     pub fn add_edge(&mut self, from: &str, to: &str, weight: i32) {
-        self.adjacency_list
-            .entry(from.to_string())
-            .or_insert(vec![])
-            .push((to.to_string(), weight));
+        self.graph.add_edge(from.to_string(), to.to_string(), weight);
     }
 
-    /// Gets the edges of a node.
+    /// Removes the edge from `from` to `to`. Returns `true` if there was an
+    /// edge.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `from` - The node the edge is from.
+    /// - `to` - The node the edge is to.
+    pub fn remove_edge(&mut self, from: &str, to: &str) -> bool {
+        self.graph.remove_edge(from, to)
+    }
+
+    /// Removes a node and all edges from and to it. Returns `true` if the
+    /// node existed.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `node` - The node to remove.
+    pub fn remove_node(&mut self, node: &str) -> bool {
+        self.graph.remove_node(node)
+    }
+
+    /// Returns the weight of the edge from `from` to `to` or `None`, if there
+    /// is no such edge.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `from` - The node the edge is from.
+    /// - `to` - The node the edge is to.
+    pub fn edge_weight(&self, from: &str, to: &str) -> Option<i32> {
+        self.graph.edge_weight(from, to)
+    }
+
+    /// Returns `true` if there is an edge from `from` to `to`.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `from` - The node the edge is from.
+    /// - `to` - The node the edge is to.
+    pub fn has_edge(&self, from: &str, to: &str) -> bool {
+        self.graph.has_edge(from, to)
+    }
+
+    /// Gets the edges of a node or `None`, if the node does not exist. A node
+    /// that is only the end of edges has no edges.
     /// 
     /// # Arguments
     /// 
     /// - `node` - The node to get the edges of.
-    pub fn get_edges(&self, node: &str) -> Option<&Vec<(String, i32)>> {
-        self.adjacency_list.get(node)
+    pub fn get_edges(&self, node: &str) -> Option<Vec<(String, i32)>> {
+        self.graph.index_of(node).map(|index| {
+            self.graph.edges(index).iter()
+                .map(|&(to, weight)| (self.graph.node(to).clone(), weight))
+                .collect()
+        })
+    }
+
+    /// Returns the underlying [`IndexedGraph`].
+    pub fn as_indexed(&self) -> &IndexedGraph<String, i32> {
+        &self.graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_edge_should_add_edge_from_source_node_only() {
+        let mut graph = DirectedGraph::new();
+
+        graph.add_edge("London", "Dublin", 464);
+
+        assert_eq!(graph.get_edges("London"), Some(vec![("Dublin".to_string(), 464)]));
+        assert_eq!(graph.get_edges("Dublin"), Some(vec![]));
+        assert_eq!(graph.get_edges("Paris"), None);
+    }
+
+    #[test]
+    fn add_node_should_add_node_without_edges() {
+        let mut graph = DirectedGraph::new();
+
+        graph.add_node("London");
+
+        assert_eq!(graph.get_edges("London"), Some(vec![]));
+    }
+
+    #[test]
+    fn add_edge_should_keep_parallel_edges() {
+        let mut graph = DirectedGraph::new();
+
+        graph.add_edge("London", "Dublin", 464);
+        graph.add_edge("London", "Dublin", 500);
+
+        assert_eq!(
+            graph.get_edges("London"),
+            Some(vec![("Dublin".to_string(), 464), ("Dublin".to_string(), 500)]));
+    }
+
+    #[test]
+    fn add_edge_should_replace_weight_with_replace_policy() {
+        let mut graph = DirectedGraph::new().with_policy(EdgePolicy::Replace);
+
+        graph.add_edge("London", "Dublin", 464);
+        graph.add_edge("London", "Dublin", 500);
+
+        assert_eq!(graph.get_edges("London"), Some(vec![("Dublin".to_string(), 500)]));
+    }
+
+    #[test]
+    fn remove_node_should_remove_edges_to_it() {
+        let mut graph = DirectedGraph::new();
+
+        graph.add_edge("London", "Dublin", 464);
+        graph.add_edge("London", "Paris", 344);
+        graph.add_edge("Dublin", "Paris", 500);
+
+        assert!(graph.remove_node("Dublin"));
+        assert_eq!(graph.get_edges("London"), Some(vec![("Paris".to_string(), 344)]));
+        assert_eq!(graph.get_edges("Dublin"), None);
+        assert!(graph.remove_edge("London", "Paris"));
+        assert_eq!(graph.get_edges("London"), Some(vec![]));
     }
 }
@@ -0,0 +1,41 @@
+use std::{hash::Hash, ops::Add};
+
+use crate::{directed_graph::DirectedGraph, undirected_graph::UndirectedGraph};
+
+/// A graph whose edges are looked up by node. The search algorithms of this
+/// crate, e. g. [`dijkstra`](crate::dijkstra::dijkstra), work on any `Graph`,
+/// so implicit graphs like [`GridGraph`](crate::grid_graph::GridGraph) do not
+/// have to be built node by node.
+pub trait Graph {
+    /// The type of the nodes.
+    type Node: Clone + Eq + Hash;
+
+    /// The type of the edge weights. `Default` has to be the zero weight.
+    type Weight: Copy + Ord + Add<Output = Self::Weight> + Default;
+
+    /// Returns the nodes that can be reached from `node` with a single edge
+    /// together with the weight of the edge.
+    ///
+    /// # Arguments
+    ///
+    /// - `node` - The node to get the neighbors of.
+    fn neighbors(&self, node: &Self::Node) -> Vec<(Self::Node, Self::Weight)>;
+}
+
+impl Graph for UndirectedGraph {
+    type Node = String;
+    type Weight = i32;
+
+    fn neighbors(&self, node: &String) -> Vec<(String, i32)> {
+        self.get_edges(node)
+    }
+}
+
+impl Graph for DirectedGraph {
+    type Node = String;
+    type Weight = i32;
+
+    fn neighbors(&self, node: &String) -> Vec<(String, i32)> {
+        self.get_edges(node).unwrap_or_default()
+    }
+}
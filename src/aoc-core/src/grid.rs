@@ -0,0 +1,722 @@
+use std::{
+    iter::Sum,
+    ops::{Index, IndexMut},
+};
+
+use thiserror::Error;
+
+use crate::primitives::{Direction, Position, Rect};
+
+/// Errors that can happen while accessing the cells of a [`Grid`].
+#[derive(Error, Debug, Clone, Eq, PartialEq)]
+pub enum GridError {
+    /// The position is outside of the grid.
+    #[error("Access is out of bounds: (x={}, y={}) (width={1}, height={2})!", .0.x, .0.y)]
+    OutOfBounds(Position, usize, usize),
+
+    /// The slice from the first to the second position is neither
+    /// horizontal, vertical nor diagonal.
+    #[error(
+        "Only horizontal, vertical or diagonal slices are possible: (x1={}, y1={})(x2={}, y2={})!",
+        .0.x, .0.y, .1.x, .1.y)]
+    InvalidSlice(Position, Position),
+
+    /// The rectangle is not normalized, i. e. `x2 < x1` or `y2 < y1`.
+    #[error("Only rectangles allowed, where the rectangle is normalized (x2 < x1 || y2 < y1): {0:?}")]
+    NotNormalized(Rect),
+}
+
+/// A cell that differs between two grids. A value is `None`, if the cell is
+/// outside of the respective grid.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CellChange<T> {
+    /// The position of the cell.
+    pub pos: Position,
+
+    /// The value in the first grid.
+    pub old: Option<T>,
+
+    /// The value in the second grid.
+    pub new: Option<T>,
+}
+
+/// A generic 2D grid that stores its cells row by row. `TextMap`, `CharGrid`
+/// and `IntGrid` are grids of characters and integers.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    /// The cells of the grid, row by row.
+    pub(crate) data: Vec<T>,
+
+    /// The width of the grid.
+    pub(crate) width: usize,
+
+    /// The height of the grid.
+    pub(crate) height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a new `Grid` with the given `width` and `height`. All cells
+    /// are initialized with `init`.
+    ///
+    /// # Arguments
+    ///
+    /// - `width` - The width of the grid.
+    /// - `height` - The height of the grid.
+    /// - `init` - The value to initialize all cells with.
+    pub fn new(width: usize, height: usize, init: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            data: vec![init; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Creates a new `Grid` from cells that are given row by row.
+    ///
+    /// The method panics if the number of cells is not `width * height`.
+    ///
+    /// # Arguments
+    ///
+    /// - `width` - The width of the grid.
+    /// - `height` - The height of the grid.
+    /// - `data` - The cells of the grid, row by row.
+    pub fn from_vec(width: usize, height: usize, data: Vec<T>) -> Self {
+        if data.len() != width * height {
+            panic!(
+                "The number of cells does not match the size of the grid: {} (width={}, height={})!",
+                data.len(), width, height);
+        }
+
+        Self {
+            data,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the width of the grid.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the grid.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the rectangle that covers the whole grid. It can be used as
+    /// bounds for [`Position::checked_step`].
+    pub fn bounds(&self) -> Rect {
+        Rect::from_size(self.width, self.height)
+    }
+
+    /// Returns `true` if `pos` is inside of the grid.
+    ///
+    /// # Arguments
+    ///
+    /// - `pos` - The position to check.
+    pub fn in_bounds(&self, pos: Position) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    /// Returns the value at the given `pos` or `None`, if `pos` is outside of
+    /// the grid.
+    ///
+    /// # Arguments
+    ///
+    /// - `pos` - The position to get the value from.
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.index_of(pos).map(|index| &self.data[index])
+    }
+
+    /// Returns a mutable reference to the value at the given `pos` or `None`,
+    /// if `pos` is outside of the grid.
+    ///
+    /// # Arguments
+    ///
+    /// - `pos` - The position to get the value from.
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.index_of(pos).map(|index| &mut self.data[index])
+    }
+
+    /// Sets the value at the given `pos` to `value`. The method panics if
+    /// `pos` is outside of the grid.
+    ///
+    /// # Arguments
+    ///
+    /// - `pos` - The position to set the value at.
+    /// - `value` - The value to set.
+    pub fn set(&mut self, pos: Position, value: T) {
+        self[pos] = value;
+    }
+
+    /// Returns the row `y` of the grid.
+    ///
+    /// # Arguments
+    ///
+    /// - `y` - The y-coordinate of the row.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Fills the rectangle defined by `top_left` and `bottom_right` with
+    /// `value`.
+    ///
+    /// # Arguments
+    ///
+    /// - `top_left` - The top-left corner of the rectangle.
+    /// - `bottom_right` - The bottom-right corner of the rectangle.
+    /// - `value` - The value to fill the rectangle with.
+    pub fn fill_rect(&mut self, top_left: Position, bottom_right: Position, value: T)
+    where
+        T: Clone,
+    {
+        for y in top_left.y..=bottom_right.y {
+            for x in top_left.x..=bottom_right.x {
+                self.set(Position { x, y }, value.clone());
+            }
+        }
+    }
+
+    /// Counts the cells that are equal to `value`.
+    ///
+    /// # Arguments
+    ///
+    /// - `value` - The value to count.
+    pub fn count(&self, value: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        self.data.iter().filter(|&cell| cell == value).count()
+    }
+
+    /// Returns the position of the first cell, row by row, that is equal to
+    /// `value`.
+    ///
+    /// # Arguments
+    ///
+    /// - `value` - The value to find.
+    pub fn position(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.data.iter()
+            .position(|cell| cell == value)
+            .map(|index| self.position_of(index))
+    }
+
+    /// Returns an iterator over the positions and values of all cells, row by
+    /// row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
+        self.data.iter()
+            .enumerate()
+            .map(move |(index, value)| (self.position_of(index), value))
+    }
+
+    /// Returns an iterator over the positions of all cells that match the
+    /// `predicate`, row by row.
+    ///
+    /// # Arguments
+    ///
+    /// - `predicate` - The predicate the value of a cell has to match.
+    pub fn positions<'a>(&'a self, predicate: impl Fn(&T) -> bool + 'a)
+        -> impl Iterator<Item = Position> + 'a
+    {
+        self.iter()
+            .filter(move |(_, value)| predicate(value))
+            .map(|(pos, _)| pos)
+    }
+
+    /// Returns an iterator over the horizontal and vertical neighbors of
+    /// `pos` that are inside of the grid, clockwise starting with north.
+    ///
+    /// # Arguments
+    ///
+    /// - `pos` - The position to get the neighbors of.
+    pub fn neighbors4(&self, pos: Position) -> impl Iterator<Item = Position> {
+        self.neighbors(pos, &Direction::CARDINAL)
+    }
+
+    /// Returns an iterator over the horizontal, vertical and diagonal
+    /// neighbors of `pos` that are inside of the grid, clockwise starting
+    /// with north.
+    ///
+    /// # Arguments
+    ///
+    /// - `pos` - The position to get the neighbors of.
+    pub fn neighbors8(&self, pos: Position) -> impl Iterator<Item = Position> {
+        self.neighbors(pos, &Direction::ALL)
+    }
+
+    /// Returns an iterator over the positions from `pos` into `direction`.
+    /// The iterator starts with `pos` itself and stops at the edge of the
+    /// grid. It is empty, if `pos` is outside of the grid.
+    ///
+    /// # Arguments
+    ///
+    /// - `pos` - The position to start at.
+    /// - `direction` - The direction to walk into.
+    pub fn ray(&self, pos: Position, direction: Direction) -> impl Iterator<Item = Position> {
+        let bounds = self.bounds();
+        let start = self.in_bounds(pos).then_some(pos);
+
+        std::iter::successors(start, move |pos| pos.checked_step(direction, bounds))
+    }
+
+    fn neighbors(&self, pos: Position, directions: &'static [Direction])
+        -> impl Iterator<Item = Position>
+    {
+        let bounds = self.bounds();
+        let inside = self.in_bounds(pos);
+
+        directions.iter()
+            .filter(move |_| inside)
+            .filter_map(move |&direction| pos.checked_step(direction, bounds))
+    }
+
+    /// Returns a new grid that is rotated clockwise by 90 degrees. Width and
+    /// height are swapped.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(self.height, self.width, |x, y| Position::new(y, self.height - 1 - x))
+    }
+
+    /// Returns a new grid that is rotated counterclockwise by 90 degrees.
+    /// Width and height are swapped.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(self.height, self.width, |x, y| Position::new(self.width - 1 - y, x))
+    }
+
+    /// Returns a new grid that is mirrored at the diagonal from the top-left
+    /// to the bottom-right corner, i. e. rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(self.height, self.width, |x, y| Position::new(y, x))
+    }
+
+    /// Returns a new grid that is mirrored horizontally, i. e. left and right
+    /// are swapped.
+    pub fn flip_h(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(self.width, self.height, |x, y| Position::new(self.width - 1 - x, y))
+    }
+
+    /// Returns a new grid that is mirrored vertically, i. e. top and bottom
+    /// are swapped.
+    pub fn flip_v(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(self.width, self.height, |x, y| Position::new(x, self.height - 1 - y))
+    }
+
+    /// Returns a new grid with the cells inside of the rectangle `r`. The
+    /// method panics if the rectangle is not normalized or out of bounds.
+    ///
+    /// # Arguments
+    ///
+    /// - `r` - The rectangle to crop the grid to.
+    pub fn crop(&self, r: Rect) -> Self
+    where
+        T: Clone,
+    {
+        self.try_crop(r).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Returns a new grid with the cells inside of the rectangle `r` like
+    /// [`Grid::crop`], but returns an error instead of panicking.
+    ///
+    /// # Arguments
+    ///
+    /// - `r` - The rectangle to crop the grid to.
+    pub fn try_crop(&self, r: Rect) -> Result<Self, GridError>
+    where
+        T: Clone,
+    {
+        if r.is_empty() {
+            return Err(GridError::NotNormalized(r));
+        }
+
+        let bottom_right = Position::new(r.x2, r.y2);
+
+        if !self.in_bounds(bottom_right) {
+            return Err(GridError::OutOfBounds(bottom_right, self.width, self.height));
+        }
+
+        let mut data: Vec<T> = vec![];
+
+        for y in r.y1..=r.y2 {
+            data.extend_from_slice(&self.row(y)[r.x1..=r.x2]);
+        }
+
+        Ok(Self::from_vec(r.x2 - r.x1 + 1, r.y2 - r.y1 + 1, data))
+    }
+
+    /// Returns a new grid with a border of `n` cells on every side that are
+    /// initialized with `fill`.
+    ///
+    /// # Arguments
+    ///
+    /// - `n` - The width of the border.
+    /// - `fill` - The value of the cells of the border.
+    pub fn pad(&self, n: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut padded = Self::new(self.width + 2 * n, self.height + 2 * n, fill);
+
+        for (pos, value) in self.iter() {
+            padded.set(Position::new(pos.x + n, pos.y + n), value.clone());
+        }
+
+        padded
+    }
+
+    /// Returns a new grid of the given size, where every cell `(x, y)` is
+    /// the value of this grid at `source(x, y)`.
+    fn transformed(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> Position)
+        -> Self
+    where
+        T: Clone,
+    {
+        let mut data: Vec<T> = Vec::with_capacity(width * height);
+
+        for y in 0..height {
+            for x in 0..width {
+                data.push(self[source(x, y)].clone());
+            }
+        }
+
+        Self::from_vec(width, height, data)
+    }
+
+    /// Returns all cells, row by row, that differ between this grid and
+    /// `other`. If the grids have different sizes, the cells that are only
+    /// part of one of the grids are changed as well.
+    ///
+    /// # Arguments
+    ///
+    /// - `other` - The grid to compare with.
+    pub fn diff(&self, other: &Self) -> Vec<CellChange<T>>
+    where
+        T: Clone + PartialEq,
+    {
+        let mut changes: Vec<CellChange<T>> = vec![];
+
+        for y in 0..self.height.max(other.height) {
+            for x in 0..self.width.max(other.width) {
+                let pos = Position::new(x, y);
+                let old = self.get(pos);
+                let new = other.get(pos);
+
+                if old != new {
+                    changes.push(CellChange { pos, old: old.cloned(), new: new.cloned() });
+                }
+            }
+        }
+
+        changes
+    }
+
+    /// Returns a new grid of the same size with `f` applied to every cell.
+    ///
+    /// # Arguments
+    ///
+    /// - `f` - The function to apply to every cell.
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            data: self.data.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Returns the sum of all cells.
+    pub fn sum(&self) -> T
+    where
+        T: Sum<T> + Copy,
+    {
+        self.data.iter().copied().sum()
+    }
+
+    fn index_of(&self, pos: Position) -> Option<usize> {
+        self.in_bounds(pos).then(|| pos.y * self.width + pos.x)
+    }
+
+    fn expect_index_of(&self, pos: Position) -> usize {
+        self.index_of(pos)
+            .unwrap_or_else(|| panic!("{}", GridError::OutOfBounds(pos, self.width, self.height)))
+    }
+
+    fn position_of(&self, index: usize) -> Position {
+        Position {
+            x: index % self.width,
+            y: index / self.width,
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    /// Returns the value at the given `pos`. Panics if `pos` is outside of
+    /// the grid.
+    fn index(&self, pos: Position) -> &T {
+        &self.data[self.expect_index_of(pos)]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    /// Returns a mutable reference to the value at the given `pos`. Panics if
+    /// `pos` is outside of the grid.
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        let index = self.expect_index_of(pos);
+
+        &mut self.data[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn grid() -> Grid<i32> {
+        Grid::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6])
+    }
+
+    #[test]
+    fn from_vec_should_panic_when_size_does_not_match() {
+        assert!(std::panic::catch_unwind(|| Grid::from_vec(3, 2, vec![1, 2, 3])).is_err());
+    }
+
+    #[test]
+    fn get_should_return_none_outside_of_grid() {
+        let grid = grid();
+
+        assert_eq!(Some(&3), grid.get(Position::new(2, 0)));
+        assert_eq!(None, grid.get(Position::new(3, 0)));
+        assert_eq!(None, grid.get(Position::new(0, 2)));
+    }
+
+    #[test]
+    fn get_mut_should_change_value() {
+        let mut grid = grid();
+
+        *grid.get_mut(Position::new(1, 1)).unwrap() = 42;
+
+        assert_eq!(42, grid[Position::new(1, 1)]);
+        assert_eq!(None, grid.get_mut(Position::new(3, 1)));
+    }
+
+    #[test]
+    fn set_should_panic_instead_of_wrapping_to_next_row() {
+        let mut grid = grid();
+
+        assert!(std::panic::catch_unwind(move || grid.set(Position::new(3, 0), 0)).is_err());
+    }
+
+    #[test]
+    fn in_bounds_should_be_false_for_empty_grid() {
+        let grid: Grid<i32> = Grid::from_vec(0, 0, vec![]);
+
+        assert!(!grid.in_bounds(Position::new(0, 0)));
+    }
+
+    #[test]
+    fn row_should_return_cells_of_row() {
+        assert_eq!(&[4, 5, 6], grid().row(1));
+    }
+
+    #[test]
+    fn position_should_return_first_match_row_by_row() {
+        let grid = Grid::from_vec(2, 2, vec!['.', '#', '#', '.']);
+
+        assert_eq!(Some(Position { x: 1, y: 0 }), grid.position(&'#'));
+        assert_eq!(None, grid.position(&'X'));
+    }
+
+    #[test]
+    fn iter_should_return_positions_row_by_row() {
+        let cells: Vec<(Position, i32)> = grid().iter().map(|(pos, &v)| (pos, v)).collect();
+
+        assert_eq!((Position { x: 0, y: 0 }, 1), cells[0]);
+        assert_eq!((Position { x: 2, y: 0 }, 3), cells[2]);
+        assert_eq!((Position { x: 0, y: 1 }, 4), cells[3]);
+    }
+
+    #[test]
+    fn positions_should_return_matching_cells() {
+        let even: Vec<Position> = grid().positions(|v| v % 2 == 0).collect();
+
+        assert_eq!(
+            vec![Position { x: 1, y: 0 }, Position { x: 0, y: 1 }, Position { x: 2, y: 1 }],
+            even);
+    }
+
+    #[test]
+    fn neighbors4_should_skip_neighbors_outside_of_grid() {
+        let neighbors: Vec<Position> = grid().neighbors4(Position::new(0, 0)).collect();
+
+        assert_eq!(vec![Position::new(1, 0), Position::new(0, 1)], neighbors);
+    }
+
+    #[test]
+    fn neighbors8_should_return_all_neighbors_clockwise() {
+        let neighbors: Vec<Position> = grid().neighbors8(Position::new(1, 1)).collect();
+
+        assert_eq!(
+            vec![
+                Position::new(1, 0),
+                Position::new(2, 0),
+                Position::new(2, 1),
+                Position::new(0, 1),
+                Position::new(0, 0),
+            ],
+            neighbors);
+    }
+
+    #[test]
+    fn neighbors_should_be_empty_outside_of_grid() {
+        assert_eq!(0, grid().neighbors8(Position::new(5, 5)).count());
+    }
+
+    #[test]
+    fn ray_should_start_at_pos_and_stop_at_edge() {
+        let ray: Vec<Position> = grid().ray(Position::new(0, 1), Direction::NorthEast).collect();
+
+        assert_eq!(vec![Position::new(0, 1), Position::new(1, 0)], ray);
+    }
+
+    #[test]
+    fn ray_should_be_empty_outside_of_grid() {
+        assert_eq!(0, grid().ray(Position::new(3, 0), Direction::West).count());
+    }
+
+    #[test]
+    fn map_should_keep_size() {
+        let mapped = grid().map(|v| v * 10);
+
+        assert_eq!((3, 2), (mapped.width(), mapped.height()));
+        assert_eq!(vec![10, 20, 30, 40, 50, 60], mapped.data);
+    }
+
+    #[test]
+    fn diff_should_list_changed_cells() {
+        let mut other = grid();
+        other.set(Position::new(1, 1), 0);
+
+        assert_eq!(
+            vec![CellChange { pos: Position::new(1, 1), old: Some(5), new: Some(0) }],
+            grid().diff(&other));
+    }
+
+    #[test]
+    fn diff_should_list_cells_outside_of_smaller_grid() {
+        let other = Grid::from_vec(2, 2, vec![1, 2, 4, 5]);
+
+        assert_eq!(
+            vec![
+                CellChange { pos: Position::new(2, 0), old: Some(3), new: None },
+                CellChange { pos: Position::new(2, 1), old: Some(6), new: None },
+            ],
+            grid().diff(&other));
+    }
+
+    #[test]
+    fn rotate_cw_should_swap_width_and_height() {
+        let rotated = grid().rotate_cw();
+
+        assert_eq!((2, 3), (rotated.width(), rotated.height()));
+        assert_eq!(vec![4, 1, 5, 2, 6, 3], rotated.data);
+    }
+
+    #[test]
+    fn rotate_ccw_should_rotate_counterclockwise() {
+        assert_eq!(vec![3, 6, 2, 5, 1, 4], grid().rotate_ccw().data);
+    }
+
+    #[test]
+    fn flip_h_and_flip_v_should_mirror_grid() {
+        assert_eq!(vec![3, 2, 1, 6, 5, 4], grid().flip_h().data);
+        assert_eq!(vec![4, 5, 6, 1, 2, 3], grid().flip_v().data);
+    }
+
+    #[test]
+    fn crop_should_return_cells_inside_of_rect() {
+        let cropped = grid().crop(Rect::new(1, 0, 2, 1));
+
+        assert_eq!((2, 2), (cropped.width(), cropped.height()));
+        assert_eq!(vec![2, 3, 5, 6], cropped.data);
+    }
+
+    #[test]
+    fn try_crop_should_fail_when_rect_is_out_of_bounds() {
+        assert_eq!(
+            Err(GridError::OutOfBounds(Position::new(3, 1), 3, 2)),
+            grid().try_crop(Rect::new(0, 0, 3, 1)));
+    }
+
+    #[test]
+    fn pad_should_add_border_on_every_side() {
+        let padded = Grid::from_vec(1, 1, vec![1]).pad(1, 0);
+
+        assert_eq!((3, 3), (padded.width(), padded.height()));
+        assert_eq!(vec![0, 0, 0, 0, 1, 0, 0, 0, 0], padded.data);
+    }
+
+    fn any_grid() -> impl Strategy<Value = Grid<u8>> {
+        (0..6usize, 0..6usize).prop_flat_map(|(width, height)| {
+            proptest::collection::vec(any::<u8>(), width * height)
+                .prop_map(move |data| Grid::from_vec(width, height, data))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn four_rotations_should_give_back_the_original(grid in any_grid()) {
+            prop_assert_eq!(&grid, &grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw());
+            prop_assert_eq!(&grid, &grid.rotate_ccw().rotate_ccw().rotate_ccw().rotate_ccw());
+        }
+
+        #[test]
+        fn rotate_ccw_should_undo_rotate_cw(grid in any_grid()) {
+            prop_assert_eq!(&grid, &grid.rotate_cw().rotate_ccw());
+        }
+
+        #[test]
+        fn mirroring_twice_should_give_back_the_original(grid in any_grid()) {
+            prop_assert_eq!(&grid, &grid.transpose().transpose());
+            prop_assert_eq!(&grid, &grid.flip_h().flip_h());
+            prop_assert_eq!(&grid, &grid.flip_v().flip_v());
+        }
+
+        #[test]
+        fn rotate_cw_should_be_transpose_and_flip_h(grid in any_grid()) {
+            prop_assert_eq!(grid.rotate_cw(), grid.transpose().flip_h());
+        }
+
+        #[test]
+        fn crop_should_undo_pad(grid in any_grid(), n in 0..3usize) {
+            prop_assume!(grid.width() > 0 && grid.height() > 0);
+
+            let padded = grid.pad(n, 0);
+            let inner = Rect::new(n, n, n + grid.width() - 1, n + grid.height() - 1);
+
+            prop_assert_eq!(grid, padded.crop(inner));
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use std::ops::Add;
+
+use crate::{
+    graph::Graph,
+    grid::Grid,
+    primitives::{Direction, Position},
+    region::Connectivity,
+};
+
+/// A graph view of a grid, where the nodes are the positions of the cells.
+/// The `cost` closure is called with the values of two neighboring cells and
+/// returns the weight of the step from the first to the second cell, or
+/// `None` if the step is not possible.
+pub struct GridGraph<'a, T, F> {
+    grid: &'a Grid<T>,
+    connectivity: Connectivity,
+    cost: F,
+}
+
+impl<'a, T, W, F> GridGraph<'a, T, F>
+where
+    F: Fn(&T, &T) -> Option<W>,
+{
+    /// Creates a new `GridGraph` over `grid`.
+    ///
+    /// # Arguments
+    ///
+    /// - `grid` - The grid to walk on.
+    /// - `connectivity` - Defines which cells are neighbors.
+    /// - `cost` - Returns the weight of a step between the values of two
+    ///   cells, or `None` if the step is not possible.
+    pub fn new(grid: &'a Grid<T>, connectivity: Connectivity, cost: F) -> Self {
+        Self { grid, connectivity, cost }
+    }
+}
+
+impl<T, W, F> Graph for GridGraph<'_, T, F>
+where
+    F: Fn(&T, &T) -> Option<W>,
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    type Node = Position;
+    type Weight = W;
+
+    fn neighbors(&self, pos: &Position) -> Vec<(Position, W)> {
+        let neighbors: Vec<Position> = match self.connectivity {
+            Connectivity::Four => self.grid.neighbors4(*pos).collect(),
+            Connectivity::Eight => self.grid.neighbors8(*pos).collect(),
+        };
+
+        neighbors.into_iter()
+            .filter_map(|next| {
+                (self.cost)(&self.grid[*pos], &self.grid[next]).map(|weight| (next, weight))
+            })
+            .collect()
+    }
+}
+
+/// A graph view of a grid for puzzles where turning has a cost. The nodes
+/// are the positions of the cells together with the direction one is facing.
+/// From every node one can step forward, if the `cost` closure allows it, or
+/// turn by 90 degrees to the left or right for `turn_cost`.
+pub struct OrientedGridGraph<'a, T, W, F> {
+    grid: &'a Grid<T>,
+    cost: F,
+    turn_cost: W,
+}
+
+impl<'a, T, W, F> OrientedGridGraph<'a, T, W, F>
+where
+    F: Fn(&T, &T) -> Option<W>,
+{
+    /// Creates a new `OrientedGridGraph` over `grid`.
+    ///
+    /// # Arguments
+    ///
+    /// - `grid` - The grid to walk on.
+    /// - `cost` - Returns the weight of a step between the values of two
+    ///   cells, or `None` if the step is not possible.
+    /// - `turn_cost` - The weight of turning by 90 degrees.
+    pub fn new(grid: &'a Grid<T>, cost: F, turn_cost: W) -> Self {
+        Self { grid, cost, turn_cost }
+    }
+}
+
+impl<T, W, F> Graph for OrientedGridGraph<'_, T, W, F>
+where
+    F: Fn(&T, &T) -> Option<W>,
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    type Node = (Position, Direction);
+    type Weight = W;
+
+    fn neighbors(&self, &(pos, direction): &(Position, Direction))
+        -> Vec<((Position, Direction), W)>
+    {
+        let mut neighbors = vec![
+            ((pos, direction.turn_left()), self.turn_cost),
+            ((pos, direction.turn_right()), self.turn_cost),
+        ];
+
+        if let Some(next) = pos.checked_step(direction, self.grid.bounds()) {
+            if let Some(weight) = (self.cost)(&self.grid[pos], &self.grid[next]) {
+                neighbors.insert(0, ((next, direction), weight));
+            }
+        }
+
+        neighbors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dijkstra::dijkstra, text_map::TextMap};
+
+    const MAZE: &str = r"S.#.
+.##.
+...E";
+
+    fn walkable(_: &char, to: &char) -> Option<i32> {
+        (*to != '#').then_some(1)
+    }
+
+    #[test]
+    fn neighbors_should_skip_walls_and_edges() {
+        let maze = TextMap::try_from(MAZE).unwrap();
+        let graph = GridGraph::new(&maze, Connectivity::Four, walkable);
+
+        assert_eq!(vec![(Position::new(0, 0), 1)], graph.neighbors(&Position::new(1, 0)));
+    }
+
+    #[test]
+    fn dijkstra_should_find_shortest_path_through_grid() {
+        let maze = TextMap::try_from(MAZE).unwrap();
+        let graph = GridGraph::new(&maze, Connectivity::Four, walkable);
+
+        let (path, cost) = dijkstra(&graph, &Position::new(0, 0), &Position::new(3, 2)).unwrap();
+
+        assert_eq!(5, cost);
+        assert_eq!(Position::new(3, 2), path[path.len() - 1]);
+    }
+
+    #[test]
+    fn dijkstra_should_include_turn_costs_in_oriented_grid() {
+        let maze = TextMap::try_from(MAZE).unwrap();
+        let graph = OrientedGridGraph::new(&maze, walkable, 1000);
+
+        let start = (Position::new(0, 0), Direction::East);
+        let goals = [Direction::North, Direction::East, Direction::South, Direction::West]
+            .map(|direction| dijkstra(&graph, &start, &(Position::new(3, 2), direction)));
+        let cost = goals.iter().flatten().map(|(_, cost)| *cost).min();
+
+        // turn south, walk down two cells, turn east and walk three cells
+        assert_eq!(Some(2005), cost);
+    }
+}
@@ -0,0 +1,436 @@
+use std::{borrow::Borrow, collections::HashMap, hash::Hash, ops::Add};
+
+use crate::graph::Graph;
+
+/// Defines what happens if an edge is added between two nodes that are
+/// already connected.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum EdgePolicy {
+    /// The weight of the existing edge is replaced.
+    #[default]
+    Replace,
+
+    /// The smaller weight is kept.
+    KeepMin,
+
+    /// The larger weight is kept.
+    KeepMax,
+
+    /// Another edge is added, i. e. there can be several edges between two
+    /// nodes.
+    Multi,
+}
+
+/// A graph that stores its nodes once and refers to them by their index.
+/// The edges are kept in an adjacency list per node, so looking up the
+/// edges of a node does not scan all edges.
+#[derive(Debug, Clone)]
+pub struct IndexedGraph<N, W> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    adjacency: Vec<Vec<(usize, W)>>,
+    directed: bool,
+    policy: EdgePolicy,
+}
+
+impl<N, W> IndexedGraph<N, W>
+where
+    N: Clone + Eq + Hash,
+    W: Copy + Ord,
+{
+    /// Creates a new graph, where every edge only leads from one node to the
+    /// other.
+    pub fn directed() -> Self {
+        Self::new(true)
+    }
+
+    /// Creates a new graph, where every edge leads in both directions.
+    pub fn undirected() -> Self {
+        Self::new(false)
+    }
+
+    fn new(directed: bool) -> Self {
+        Self {
+            nodes: vec![],
+            indices: HashMap::new(),
+            adjacency: vec![],
+            directed,
+            policy: EdgePolicy::default(),
+        }
+    }
+
+    /// Sets the policy for adding edges between nodes that are already
+    /// connected. The default is [`EdgePolicy::Replace`].
+    ///
+    /// # Arguments
+    ///
+    /// - `policy` - The policy for adding edges.
+    pub fn with_policy(mut self, policy: EdgePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Returns the policy for adding edges.
+    pub fn policy(&self) -> EdgePolicy {
+        self.policy
+    }
+
+    /// Returns `true` if the edges of the graph only lead in one direction.
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Adds a node to the graph and returns its index. If the node already
+    /// exists, the index of the existing node is returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `node` - The node to add to the graph.
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(&index) = self.indices.get(&node) {
+            return index;
+        }
+
+        let index = self.nodes.len();
+
+        self.indices.insert(node.clone(), index);
+        self.nodes.push(node);
+        self.adjacency.push(vec![]);
+
+        index
+    }
+
+    /// Returns the index of `node` or `None`, if the node is not part of the
+    /// graph.
+    ///
+    /// # Arguments
+    ///
+    /// - `node` - The node to look up.
+    pub fn index_of<Q>(&self, node: &Q) -> Option<usize>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.indices.get(node).copied()
+    }
+
+    /// Returns the node with the given `index`.
+    ///
+    /// # Arguments
+    ///
+    /// - `index` - The index of the node.
+    pub fn node(&self, index: usize) -> &N {
+        &self.nodes[index]
+    }
+
+    /// Returns all nodes in the order they were added.
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// Returns the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Adds an edge from `from` to `to` and, if the graph is undirected, from
+    /// `to` to `from`. Nodes that do not exist yet are added. If the nodes
+    /// are already connected, the [`EdgePolicy`] of the graph decides about
+    /// the weight.
+    ///
+    /// # Arguments
+    ///
+    /// - `from` - The node the edge is from.
+    /// - `to` - The node the edge is to.
+    /// - `weight` - The weight of the edge.
+    pub fn add_edge(&mut self, from: N, to: N, weight: W) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+
+        self.insert_edge(from, to, weight);
+
+        if !self.directed && from != to {
+            self.insert_edge(to, from, weight);
+        }
+    }
+
+    fn insert_edge(&mut self, from: usize, to: usize, weight: W) {
+        let policy = self.policy;
+        let existing = self.adjacency[from].iter_mut().find(|(other, _)| *other == to);
+
+        match (existing, policy) {
+            (Some((_, existing)), EdgePolicy::Replace) => *existing = weight,
+            (Some((_, existing)), EdgePolicy::KeepMin) => *existing = weight.min(*existing),
+            (Some((_, existing)), EdgePolicy::KeepMax) => *existing = weight.max(*existing),
+            _ => self.adjacency[from].push((to, weight)),
+        }
+    }
+
+    /// Removes all edges from `from` to `to` and, if the graph is undirected,
+    /// from `to` to `from`. Returns `true` if an edge was removed.
+    ///
+    /// # Arguments
+    ///
+    /// - `from` - The node the edge is from.
+    /// - `to` - The node the edge is to.
+    pub fn remove_edge<Q>(&mut self, from: &Q, to: &Q) -> bool
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (Some(from), Some(to)) = (self.index_of(from), self.index_of(to)) else {
+            return false;
+        };
+
+        let count = self.adjacency[from].len();
+        self.adjacency[from].retain(|&(other, _)| other != to);
+
+        if !self.directed {
+            self.adjacency[to].retain(|&(other, _)| other != from);
+        }
+
+        self.adjacency[from].len() != count
+    }
+
+    /// Removes `node` and all edges from and to it. Returns `true` if the
+    /// node existed. The last node takes over the index of the removed node.
+    ///
+    /// # Arguments
+    ///
+    /// - `node` - The node to remove.
+    pub fn remove_node<Q>(&mut self, node: &Q) -> bool
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let Some(index) = self.indices.remove(node) else {
+            return false;
+        };
+        let last = self.nodes.len() - 1;
+
+        self.nodes.swap_remove(index);
+        self.adjacency.swap_remove(index);
+
+        if index != last {
+            self.indices.insert(self.nodes[index].clone(), index);
+        }
+
+        for edges in self.adjacency.iter_mut() {
+            edges.retain(|&(to, _)| to != index);
+
+            for (to, _) in edges.iter_mut() {
+                if *to == last {
+                    *to = index;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Returns the weight of the edge from `from` to `to` or `None`, if there
+    /// is no such edge. If there are several edges, the smallest weight is
+    /// returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `from` - The node the edge is from.
+    /// - `to` - The node the edge is to.
+    pub fn edge_weight<Q>(&self, from: &Q, to: &Q) -> Option<W>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let to = self.index_of(to)?;
+
+        self.adjacency[self.index_of(from)?].iter()
+            .filter(|&&(other, _)| other == to)
+            .map(|&(_, weight)| weight)
+            .min()
+    }
+
+    /// Returns `true` if there is an edge from `from` to `to`. In undirected
+    /// graphs this is the same as an edge from `to` to `from`.
+    ///
+    /// # Arguments
+    ///
+    /// - `from` - The node the edge is from.
+    /// - `to` - The node the edge is to.
+    pub fn has_edge<Q>(&self, from: &Q, to: &Q) -> bool
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.edge_weight(from, to).is_some()
+    }
+
+    /// Returns the edges of the node with the given `index` as the indices of
+    /// the nodes they lead to together with their weights.
+    ///
+    /// # Arguments
+    ///
+    /// - `index` - The index of the node.
+    pub fn edges(&self, index: usize) -> &[(usize, W)] {
+        &self.adjacency[index]
+    }
+
+    /// Returns a view of the graph, where the nodes are the indices. Searching
+    /// on it does not clone the nodes.
+    pub fn by_index(&self) -> ByIndex<'_, N, W> {
+        ByIndex(self)
+    }
+}
+
+impl<N, W> Graph for IndexedGraph<N, W>
+where
+    N: Clone + Eq + Hash,
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    type Node = N;
+    type Weight = W;
+
+    fn neighbors(&self, node: &N) -> Vec<(N, W)> {
+        self.index_of(node)
+            .map(|index| {
+                self.edges(index).iter()
+                    .map(|&(to, weight)| (self.nodes[to].clone(), weight))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// A view of an [`IndexedGraph`], where the nodes are the indices.
+#[derive(Debug, Clone, Copy)]
+pub struct ByIndex<'a, N, W>(&'a IndexedGraph<N, W>);
+
+impl<N, W> Graph for ByIndex<'_, N, W>
+where
+    N: Clone + Eq + Hash,
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    type Node = usize;
+    type Weight = W;
+
+    fn neighbors(&self, &index: &usize) -> Vec<(usize, W)> {
+        self.0.edges(index).to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dijkstra::dijkstra;
+
+    #[test]
+    fn add_node_should_return_index_of_existing_node() {
+        let mut graph: IndexedGraph<&str, i32> = IndexedGraph::undirected();
+
+        assert_eq!(0, graph.add_node("London"));
+        assert_eq!(1, graph.add_node("Dublin"));
+        assert_eq!(0, graph.add_node("London"));
+        assert_eq!(2, graph.node_count());
+    }
+
+    #[test]
+    fn add_edge_should_only_add_one_direction_in_directed_graph() {
+        let mut graph: IndexedGraph<char, i32> = IndexedGraph::directed();
+
+        graph.add_edge('a', 'b', 3);
+
+        assert_eq!(vec![('b', 3)], graph.neighbors(&'a'));
+        assert_eq!(Vec::<(char, i32)>::new(), graph.neighbors(&'b'));
+    }
+
+    #[test]
+    fn add_edge_should_add_both_directions_in_undirected_graph() {
+        let mut graph: IndexedGraph<char, i32> = IndexedGraph::undirected();
+
+        graph.add_edge('a', 'b', 3);
+
+        assert_eq!(vec![('b', 3)], graph.neighbors(&'a'));
+        assert_eq!(vec![('a', 3)], graph.neighbors(&'b'));
+    }
+
+    #[test]
+    fn add_edge_should_follow_policy_for_existing_edges() {
+        let policies = [EdgePolicy::Replace, EdgePolicy::KeepMin, EdgePolicy::KeepMax];
+
+        let weights: Vec<Option<i32>> = policies.into_iter()
+            .map(|policy| {
+                let mut graph: IndexedGraph<char, i32> = IndexedGraph::undirected()
+                    .with_policy(policy);
+
+                graph.add_edge('a', 'b', 5);
+                graph.add_edge('b', 'a', 3);
+                graph.add_edge('a', 'b', 4);
+
+                assert_eq!(1, graph.neighbors(&'a').len());
+                assert_eq!(graph.edge_weight(&'a', &'b'), graph.edge_weight(&'b', &'a'));
+
+                graph.edge_weight(&'a', &'b')
+            })
+            .collect();
+
+        assert_eq!(vec![Some(4), Some(3), Some(5)], weights);
+    }
+
+    #[test]
+    fn add_edge_should_allow_parallel_edges_with_multi_policy() {
+        let mut graph: IndexedGraph<char, i32> = IndexedGraph::undirected()
+            .with_policy(EdgePolicy::Multi);
+
+        graph.add_edge('a', 'b', 5);
+        graph.add_edge('b', 'a', 3);
+
+        assert_eq!(vec![('b', 5), ('b', 3)], graph.neighbors(&'a'));
+        assert_eq!(Some(3), graph.edge_weight(&'a', &'b'));
+    }
+
+    #[test]
+    fn remove_edge_should_remove_both_directions_in_undirected_graph() {
+        let mut graph: IndexedGraph<char, i32> = IndexedGraph::undirected();
+
+        graph.add_edge('a', 'b', 5);
+
+        assert!(graph.remove_edge(&'b', &'a'));
+        assert!(!graph.has_edge(&'a', &'b'));
+        assert!(!graph.has_edge(&'b', &'a'));
+        assert!(!graph.remove_edge(&'a', &'b'));
+    }
+
+    #[test]
+    fn remove_node_should_remove_edges_and_keep_other_indices_valid() {
+        let mut graph: IndexedGraph<char, i32> = IndexedGraph::directed();
+
+        graph.add_edge('a', 'b', 1);
+        graph.add_edge('b', 'c', 2);
+        graph.add_edge('c', 'a', 3);
+
+        assert!(graph.remove_node(&'a'));
+        assert!(!graph.remove_node(&'a'));
+
+        assert_eq!(2, graph.node_count());
+        assert_eq!(vec![('c', 2)], graph.neighbors(&'b'));
+        assert_eq!(Vec::<(char, i32)>::new(), graph.neighbors(&'c'));
+        assert_eq!(Some(0), graph.index_of(&'c'));
+    }
+
+    #[test]
+    fn dijkstra_should_find_same_path_by_node_and_by_index() {
+        let mut graph: IndexedGraph<String, u32> = IndexedGraph::undirected();
+
+        graph.add_edge("London".to_string(), "Dublin".to_string(), 464);
+        graph.add_edge("Dublin".to_string(), "Paris".to_string(), 500);
+        graph.add_edge("London".to_string(), "Paris".to_string(), 1000);
+
+        let (path, cost) = dijkstra(&graph, "London", "Paris").unwrap();
+        let (indices, index_cost) = dijkstra(&graph.by_index(), &0, &2).unwrap();
+
+        assert_eq!(964, cost);
+        assert_eq!(cost, index_cost);
+        assert_eq!(
+            path,
+            indices.iter().map(|&index| graph.node(index).clone()).collect::<Vec<String>>());
+    }
+}
@@ -0,0 +1,647 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::{
+    observer::{self, InputEvent, InputObserver},
+    InputError, AOC_DATA_SUBDIR, INPUT_FILENAME,
+};
+
+/// A source for the input of a puzzle.
+pub trait InputSource {
+    /// Returns the input for the puzzle of `year` and `day`.
+    ///
+    /// # Arguments
+    ///
+    /// - `year` - The year of the puzzle.
+    /// - `day` - The day of the puzzle.
+    fn get_input(&self, year: u32, day: u32) -> Result<String, InputError>;
+}
+
+/// A source for the input of a puzzle that can also store inputs, so that
+/// they do not have to be fetched again.
+pub trait InputCache: InputSource {
+    /// Returns `true` if the input for the puzzle of `year` and `day` is in
+    /// the cache.
+    ///
+    /// # Arguments
+    ///
+    /// - `year` - The year of the puzzle.
+    /// - `day` - The day of the puzzle.
+    fn contains(&self, year: u32, day: u32) -> bool;
+
+    /// Stores the `input` for the puzzle of `year` and `day` in the cache.
+    ///
+    /// # Arguments
+    ///
+    /// - `year` - The year of the puzzle.
+    /// - `day` - The day of the puzzle.
+    /// - `input` - The input to store.
+    fn save_input(&self, year: u32, day: u32, input: &str) -> Result<(), InputError>;
+}
+
+/// The on-disk cache for the puzzle inputs. The input of a puzzle is stored
+/// in `<root>/<year>/<day>/input.txt`.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    root: PathBuf,
+    observer: Arc<dyn InputObserver>,
+}
+
+impl DiskCache {
+    /// Creates a new `DiskCache` that stores the inputs below `root`.
+    ///
+    /// # Arguments
+    ///
+    /// - `root` - The root directory of the cache.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            observer: observer::silent(),
+        }
+    }
+
+    /// Sets the observer that is notified when an input is read or saved and
+    /// returns the cache.
+    ///
+    /// # Arguments
+    ///
+    /// - `observer` - The observer.
+    pub fn with_observer(mut self, observer: Arc<dyn InputObserver>) -> Self {
+        self.observer = observer;
+
+        self
+    }
+
+    /// Creates a new `DiskCache` that stores the inputs in the data
+    /// directory of the user, i. e. `<data_dir>/aoc`.
+    pub fn data_dir() -> Result<Self, InputError> {
+        let mut root = dirs::data_dir().ok_or(InputError::DataDirNotFound)?;
+
+        root.push(AOC_DATA_SUBDIR);
+
+        Ok(Self::new(root))
+    }
+
+    /// Returns the root directory of the cache.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the directory of the puzzle of `year` and `day`.
+    ///
+    /// # Arguments
+    ///
+    /// - `year` - The year of the puzzle.
+    /// - `day` - The day of the puzzle.
+    pub fn puzzle_dir(&self, year: u32, day: u32) -> PathBuf {
+        self.root.join(year.to_string()).join(day.to_string())
+    }
+
+    fn input_file(&self, year: u32, day: u32) -> PathBuf {
+        self.puzzle_dir(year, day).join(INPUT_FILENAME)
+    }
+
+    pub(crate) fn provision_puzzle_dir(&self, year: u32, day: u32) -> Result<PathBuf, InputError> {
+        let puzzle_dir = self.puzzle_dir(year, day);
+
+        if !puzzle_dir.exists() {
+            std::fs::create_dir_all(&puzzle_dir)
+                .map_err(|e| InputError::CreateDataDir(e, format!("{:?}", puzzle_dir)))?;
+        }
+
+        Ok(puzzle_dir)
+    }
+}
+
+impl InputSource for DiskCache {
+    fn get_input(&self, year: u32, day: u32) -> Result<String, InputError> {
+        let input_file = self.input_file(year, day);
+
+        self.observer.notify(&InputEvent::ReadingInput { year, day, path: input_file.clone() });
+
+        std::fs::read_to_string(&input_file)
+            .map_err(|e| InputError::OpenInputFile(e, format!("{:?}", input_file)))
+    }
+}
+
+impl InputCache for DiskCache {
+    fn contains(&self, year: u32, day: u32) -> bool {
+        self.input_file(year, day).exists()
+    }
+
+    fn save_input(&self, year: u32, day: u32, input: &str) -> Result<(), InputError> {
+        let input_file = self.provision_puzzle_dir(year, day)?.join(INPUT_FILENAME);
+
+        self.observer.notify(&InputEvent::SavingInput { year, day, path: input_file.clone() });
+
+        std::fs::write(&input_file, input)
+            .map_err(|e| InputError::OpenInputFile(e, format!("{:?}", input_file)))
+    }
+}
+
+/// A read-only source that reads the inputs from a fixed directory. The
+/// input of a puzzle is expected in `<dir>/<year>/<day>.txt`.
+#[derive(Debug, Clone)]
+pub struct DirectorySource {
+    dir: PathBuf,
+}
+
+impl DirectorySource {
+    /// Creates a new `DirectorySource` that reads the inputs from `dir`.
+    ///
+    /// # Arguments
+    ///
+    /// - `dir` - The directory to read the inputs from.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn input_file(&self, year: u32, day: u32) -> PathBuf {
+        self.dir.join(year.to_string()).join(format!("{}.txt", day))
+    }
+}
+
+impl InputSource for DirectorySource {
+    fn get_input(&self, year: u32, day: u32) -> Result<String, InputError> {
+        let input_file = self.input_file(year, day);
+
+        if !input_file.exists() {
+            return Err(InputError::InputNotFound(year, day));
+        }
+
+        std::fs::read_to_string(&input_file)
+            .map_err(|e| InputError::OpenInputFile(e, format!("{:?}", input_file)))
+    }
+}
+
+/// A source that keeps the inputs in memory. It can be used as a cache as
+/// well, which makes it useful for tests.
+#[derive(Debug, Default)]
+pub struct InMemorySource {
+    inputs: RefCell<HashMap<(u32, u32), String>>,
+}
+
+impl InMemorySource {
+    /// Creates a new, empty `InMemorySource`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the `input` for the puzzle of `year` and `day` and returns the
+    /// source.
+    ///
+    /// # Arguments
+    ///
+    /// - `year` - The year of the puzzle.
+    /// - `day` - The day of the puzzle.
+    /// - `input` - The input of the puzzle.
+    pub fn with_input(self, year: u32, day: u32, input: &str) -> Self {
+        self.insert(year, day, input);
+
+        self
+    }
+
+    /// Adds the `input` for the puzzle of `year` and `day`. An existing input
+    /// is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// - `year` - The year of the puzzle.
+    /// - `day` - The day of the puzzle.
+    /// - `input` - The input of the puzzle.
+    pub fn insert(&self, year: u32, day: u32, input: &str) {
+        self.inputs.borrow_mut().insert((year, day), input.to_string());
+    }
+}
+
+impl InputSource for InMemorySource {
+    fn get_input(&self, year: u32, day: u32) -> Result<String, InputError> {
+        self.inputs
+            .borrow()
+            .get(&(year, day))
+            .cloned()
+            .ok_or(InputError::InputNotFound(year, day))
+    }
+}
+
+impl InputCache for InMemorySource {
+    fn contains(&self, year: u32, day: u32) -> bool {
+        self.inputs.borrow().contains_key(&(year, day))
+    }
+
+    fn save_input(&self, year: u32, day: u32, input: &str) -> Result<(), InputError> {
+        self.insert(year, day, input);
+
+        Ok(())
+    }
+}
+
+/// The default base URL of Advent of Code.
+pub const AOC_BASE_URL: &str = r"https://adventofcode.com";
+
+/// The environment variable that overrides the base URL of Advent of Code.
+pub const AOC_BASE_URL_ENV_VAR: &str = r"AOC_BASE_URL";
+
+/// The client for adventofcode.com. It downloads the inputs by using the
+/// session cookie of the user.
+///
+/// The base URL is taken from the environment variable `AOC_BASE_URL` and
+/// defaults to `https://adventofcode.com`. It can be overridden with
+/// [`AocClient::with_base_url`], e. g. to talk to a local stand-in.
+#[derive(Debug, Clone)]
+pub struct AocClient {
+    base_url: String,
+    session_cookie: Option<String>,
+    timeout: Duration,
+    observer: Arc<dyn InputObserver>,
+}
+
+/// Returns the base URL without a trailing slash, or the default base URL, if
+/// `base_url` is `None`.
+///
+/// # Arguments
+///
+/// - `base_url` - The value of the environment variable `AOC_BASE_URL`.
+fn base_url_or_default(base_url: Option<String>) -> String {
+    base_url.as_deref()
+        .unwrap_or(AOC_BASE_URL)
+        .trim_end_matches('/')
+        .to_string()
+}
+
+impl AocClient {
+    /// Creates a new `AocClient`.
+    pub fn new() -> Self {
+        Self {
+            base_url: base_url_or_default(std::env::var(AOC_BASE_URL_ENV_VAR).ok()),
+            session_cookie: None,
+            timeout: Duration::from_secs(5),
+            observer: observer::silent(),
+        }
+    }
+
+    /// Sets the base URL of Advent of Code and returns the client.
+    ///
+    /// # Arguments
+    ///
+    /// - `base_url` - The base URL, e. g. `https://adventofcode.com`.
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+
+        self
+    }
+
+    /// Sets the session cookie and returns the client. Without it, the
+    /// session cookie is looked up in the environment variable
+    /// `AOC_SESSION_COOKIE` or the file `<data_dir>/aoc/session.cookie`.
+    ///
+    /// # Arguments
+    ///
+    /// - `session_cookie` - The value of the session cookie.
+    pub fn with_session_cookie(mut self, session_cookie: &str) -> Self {
+        self.session_cookie = Some(session_cookie.trim().to_string());
+
+        self
+    }
+
+    /// Sets the timeout for reading and writing and returns the client.
+    ///
+    /// # Arguments
+    ///
+    /// - `timeout` - The timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+
+        self
+    }
+
+    /// Sets the observer that is notified before something is downloaded and
+    /// returns the client.
+    ///
+    /// # Arguments
+    ///
+    /// - `observer` - The observer.
+    pub fn with_observer(mut self, observer: Arc<dyn InputObserver>) -> Self {
+        self.observer = observer;
+
+        self
+    }
+
+    /// Returns the base URL of Advent of Code.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Downloads the HTML page of the puzzle of `year` and `day`. The session
+    /// cookie is sent if there is one, so that the page contains the second
+    /// part after the first part has been solved.
+    ///
+    /// # Arguments
+    ///
+    /// - `year` - The year of the puzzle.
+    /// - `day` - The day of the puzzle.
+    pub fn get_puzzle_page(&self, year: u32, day: u32) -> Result<String, InputError> {
+        self.observer.notify(&InputEvent::DownloadingPuzzle { year, day });
+
+        let mut request = self.agent().get(&self.puzzle_url(year, day));
+
+        if let Ok(aoc_session_cookie) = self.session_cookie() {
+            request = request.set("Cookie", &aoc_session_cookie);
+        }
+
+        request
+            .call()
+            .map_err(|e| download_error(e, year, day))?
+            .into_string()
+            .map_err(|e| read_body_error(e, year, day))
+    }
+
+    /// Posts the `answer` for `part` of the puzzle of `year` and `day` and
+    /// returns the HTML page of the response.
+    ///
+    /// # Arguments
+    ///
+    /// - `year` - The year of the puzzle.
+    /// - `day` - The day of the puzzle.
+    /// - `part` - The part of the puzzle, i. e. 1 or 2.
+    /// - `answer` - The answer to post.
+    pub fn post_answer(&self, year: u32, day: u32, part: u32, answer: &str)
+        -> Result<String, InputError>
+    {
+        let aoc_session_cookie = self.session_cookie()?;
+
+        self.agent()
+            .post(&format!("{}/answer", self.puzzle_url(year, day)))
+            .set("Cookie", &aoc_session_cookie)
+            .send_form(&[("level", part.to_string().as_str()), ("answer", answer)])
+            .map_err(|e| download_error(e, year, day))?
+            .into_string()
+            .map_err(|e| read_body_error(e, year, day))
+    }
+
+    fn puzzle_url(&self, year: u32, day: u32) -> String {
+        format!("{}/{}/day/{}", self.base_url, year, day)
+    }
+
+    fn input_url(&self, year: u32, day: u32) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    fn session_cookie(&self) -> Result<String, InputError> {
+        if let Some(cookie) = &self.session_cookie {
+            return Ok(format!("session={}", cookie));
+        }
+
+        match std::env::var("AOC_SESSION_COOKIE") {
+            Ok(cookie) => Ok(format!("session={}", cookie)),
+            Err(_) => {
+                let mut aoc_session_cookie_filepath =
+                    dirs::data_dir().ok_or(InputError::AocSessionCookieEnvVarNotSet)?;
+                aoc_session_cookie_filepath.push(AOC_DATA_SUBDIR);
+                aoc_session_cookie_filepath.push("session.cookie");
+
+                match std::fs::read_to_string(&aoc_session_cookie_filepath) {
+                    Ok(cookie) => Ok(format!("session={}", cookie.trim())),
+                    Err(_) => Err(InputError::AocSessionCookieEnvVarNotSet),
+                }
+            }
+        }
+    }
+
+    fn agent(&self) -> ureq::Agent {
+        ureq::AgentBuilder::new()
+            .timeout_connect(self.timeout)
+            .timeout_read(self.timeout)
+            .timeout_write(self.timeout)
+            .build()
+    }
+}
+
+impl Default for AocClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InputSource for AocClient {
+    fn get_input(&self, year: u32, day: u32) -> Result<String, InputError> {
+        self.observer.notify(&InputEvent::DownloadingInput { year, day });
+
+        let aoc_input_url = self.input_url(year, day);
+        let aoc_session_cookie = self.session_cookie()?;
+
+        let input_body = self.agent()
+            .get(&aoc_input_url)
+            .set("Cookie", &aoc_session_cookie)
+            .call()
+            .map_err(|e| download_error(e, year, day))?
+            .into_string()
+            .map_err(|e| read_body_error(e, year, day))?;
+
+        Ok(input_body)
+    }
+}
+
+/// Maps the error of a request to adventofcode.com to an `InputError`.
+fn download_error(e: ureq::Error, year: u32, day: u32) -> InputError {
+    match e {
+        ureq::Error::Status(404, _) => InputError::PuzzleNotUnlocked(year, day),
+        ureq::Error::Status(400, _) => InputError::InvalidSessionCookie,
+        ureq::Error::Status(status, _) if status >= 500 =>
+            InputError::ServerError(status, year, day),
+        ureq::Error::Transport(ref transport) if is_timeout(transport) =>
+            InputError::Timeout(year, day),
+        e => InputError::DownloadInput(Box::new(e), year, day),
+    }
+}
+
+/// Maps an I/O error while reading the body of a response to an `InputError`.
+fn read_body_error(e: std::io::Error, year: u32, day: u32) -> InputError {
+    match e.kind() {
+        std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock =>
+            InputError::Timeout(year, day),
+        _ => InputError::ReadInputBody(e),
+    }
+}
+
+fn is_timeout(transport: &ureq::Transport) -> bool {
+    use std::error::Error;
+
+    transport.kind() == ureq::ErrorKind::Io
+        && transport.source()
+            .and_then(|source| source.downcast_ref::<std::io::Error>())
+            .map(|e| matches!(
+                e.kind(),
+                std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock))
+            .unwrap_or(false)
+}
+
+/// A source that first looks into a cache and only fetches the input from
+/// another source, if it is not cached yet. A fetched input is stored in the
+/// cache.
+#[derive(Debug, Clone)]
+pub struct CacheThenFetch<C, F> {
+    cache: C,
+    fetcher: F,
+}
+
+impl<C: InputCache, F: InputSource> CacheThenFetch<C, F> {
+    /// Creates a new `CacheThenFetch` from the given `cache` and `fetcher`.
+    ///
+    /// # Arguments
+    ///
+    /// - `cache` - The cache to look into first.
+    /// - `fetcher` - The source to fetch the input from on a cache miss.
+    pub fn new(cache: C, fetcher: F) -> Self {
+        Self { cache, fetcher }
+    }
+
+    /// Returns the cache.
+    pub fn cache(&self) -> &C {
+        &self.cache
+    }
+
+    /// Returns the source the inputs are fetched from.
+    pub fn fetcher(&self) -> &F {
+        &self.fetcher
+    }
+}
+
+impl<C: InputCache, F: InputSource> InputSource for CacheThenFetch<C, F> {
+    fn get_input(&self, year: u32, day: u32) -> Result<String, InputError> {
+        if self.cache.contains(year, day) {
+            return self.cache.get_input(year, day);
+        }
+
+        let input = self.fetcher.get_input(year, day)?;
+
+        self.cache.save_input(year, day, &input)?;
+
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp_dir::TempDir;
+
+    #[test]
+    fn base_url_or_default_should_trim_trailing_slash() {
+        assert_eq!(
+            "http://127.0.0.1:8080",
+            base_url_or_default(Some("http://127.0.0.1:8080/".to_string())));
+    }
+
+    #[test]
+    fn base_url_or_default_should_default_to_adventofcode() {
+        assert_eq!(AOC_BASE_URL, base_url_or_default(None));
+    }
+
+    #[test]
+    fn in_memory_source_should_return_added_input() {
+        let source = InMemorySource::new().with_input(2024, 1, "3   4");
+
+        assert_eq!("3   4", source.get_input(2024, 1).unwrap());
+    }
+
+    #[test]
+    fn in_memory_source_should_return_input_not_found_for_missing_input() {
+        let source = InMemorySource::new().with_input(2024, 1, "3   4");
+
+        assert!(matches!(
+            source.get_input(2024, 2),
+            Err(InputError::InputNotFound(2024, 2))));
+    }
+
+    #[test]
+    fn disk_cache_should_return_saved_input() {
+        let root = TempDir::new("disk-cache");
+        let cache = DiskCache::new(root.path());
+
+        assert!(!cache.contains(2024, 1));
+
+        cache.save_input(2024, 1, "3   4").unwrap();
+
+        assert!(cache.contains(2024, 1));
+        assert_eq!("3   4", cache.get_input(2024, 1).unwrap());
+    }
+
+    #[test]
+    fn disk_cache_should_store_input_in_puzzle_dir() {
+        let root = TempDir::new("disk-cache-layout");
+        let cache = DiskCache::new(root.path());
+
+        cache.save_input(2024, 6, "^").unwrap();
+
+        assert!(root.join("2024").join("6").join("input.txt").exists());
+    }
+
+    #[test]
+    fn disk_cache_should_notify_observer_about_saving_and_reading() {
+        let root = TempDir::new("disk-cache-observer");
+        let observer = Arc::new(crate::observer::RecordingObserver::new());
+        let cache = DiskCache::new(root.path()).with_observer(observer.clone());
+        let path = root.join("2024").join("6").join("input.txt");
+
+        cache.save_input(2024, 6, "^").unwrap();
+        cache.get_input(2024, 6).unwrap();
+
+        assert_eq!(
+            vec![
+                InputEvent::SavingInput { year: 2024, day: 6, path: path.clone() },
+                InputEvent::ReadingInput { year: 2024, day: 6, path },
+            ],
+            observer.events());
+    }
+
+    #[test]
+    fn directory_source_should_read_input_from_directory() {
+        let dir = TempDir::new("directory-source");
+
+        std::fs::create_dir_all(dir.join("2024")).unwrap();
+        std::fs::write(dir.join("2024").join("7.txt"), "190: 10 19").unwrap();
+
+        let source = DirectorySource::new(dir.path());
+
+        assert_eq!("190: 10 19", source.get_input(2024, 7).unwrap());
+        assert!(matches!(
+            source.get_input(2024, 8),
+            Err(InputError::InputNotFound(2024, 8))));
+    }
+
+    #[test]
+    fn cache_then_fetch_should_prefer_cached_input() {
+        let source = CacheThenFetch::new(
+            InMemorySource::new().with_input(2024, 1, "cached"),
+            InMemorySource::new().with_input(2024, 1, "fetched"));
+
+        assert_eq!("cached", source.get_input(2024, 1).unwrap());
+    }
+
+    #[test]
+    fn cache_then_fetch_should_fetch_and_cache_missing_input() {
+        let source = CacheThenFetch::new(
+            InMemorySource::new(),
+            InMemorySource::new().with_input(2024, 1, "fetched"));
+
+        assert_eq!("fetched", source.get_input(2024, 1).unwrap());
+        assert!(source.cache().contains(2024, 1));
+    }
+
+    #[test]
+    fn cache_then_fetch_should_fail_if_fetcher_fails() {
+        let source = CacheThenFetch::new(
+            InMemorySource::new(), InMemorySource::new());
+
+        assert!(matches!(
+            source.get_input(2024, 1),
+            Err(InputError::InputNotFound(2024, 1))));
+        assert!(!source.cache().contains(2024, 1));
+    }
+}
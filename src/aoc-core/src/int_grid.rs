@@ -1,83 +1,68 @@
-use crate::primitives::Position;
+use std::fmt::{self, Display};
 
-/// A 2D grid of integers.
-#[derive(Debug, Clone)]
-pub struct IntGrid {
-    /// The data of the grid.
-    data: Vec<i64>,
+use crate::{grid::Grid, text_map::TextMap};
 
-    /// The width of the grid.
-    width: usize,
+/// A 2D grid of integers, a [`Grid`] of `i64`.
+pub type IntGrid = Grid<i64>;
 
-    /// The height of the grid.
-    height: usize,
-}
+/// The characters of a heatmap from the lowest to the highest value.
+pub const HEATMAP_RAMP: &str = " .:-=+*#%@";
 
-impl IntGrid {
-    /// Creates a new `IntGrid` with the given `width`, `height`, and `init_i`.
-    /// All cells are initialized with `init_i`.
-    /// 
+impl Grid<i64> {
+    /// Returns a `TextMap` that shows the values of the grid as a heatmap.
+    /// The lowest value is mapped to the first character of `ramp` and the
+    /// highest value to the last one, see [`HEATMAP_RAMP`]. If all values
+    /// are equal, the first character is used for all cells. The method
+    /// panics if `ramp` is empty.
+    ///
     /// # Arguments
-    /// 
-    /// - `width` - The width of the grid.
-    /// - `height` - The height of the grid.
-    /// - `init_i` - The integer to initialize all cells with.
-    pub fn new(width: usize, height: usize, init_i: i64) -> Self {
-        Self {
-            data: vec![init_i; width * height],
-            width,
-            height,
-        }
-    }
+    ///
+    /// - `ramp` - The characters from the lowest to the highest value.
+    pub fn heatmap(&self, ramp: &str) -> TextMap {
+        let ramp: Vec<char> = ramp.chars().collect();
 
-    /// Returns the width of the grid.
-    pub fn width(&self) -> usize {
-        self.width
-    }
+        assert!(!ramp.is_empty(), "The ramp of a heatmap needs at least one character.");
 
-    /// Returns the height of the grid.
-    pub fn height(&self) -> usize {
-        self.height
-    }
-    
-    /// Returns the integer at the given `pos`.
-    /// 
-    /// # Arguments
-    /// 
-    /// - `pos` - The position to get the integer from.
-    pub fn get(&self, pos: Position) -> i64 {
-        self.data[pos.y * self.width + pos.x]
-    }
+        let min = self.data.iter().copied().min().unwrap_or(0);
+        let max = self.data.iter().copied().max().unwrap_or(0);
+        let steps = ramp.len().saturating_sub(1) as i128;
 
-    /// Sets the integer at the given `pos` to `i`.
-    /// 
-    /// # Arguments
-    /// 
-    /// - `pos` - The position to set the integer at.
-    /// - `i` - The integer to set.
-    pub fn set(&mut self, pos: Position, i: i64) {
-        self.data[pos.y * self.width + pos.x] = i;
-    }
+        self.map(|&value| {
+            let index = if max == min {
+                0
+            } else {
+                (value as i128 - min as i128) * steps / (max as i128 - min as i128)
+            };
 
-    /// Returns the sum of all integers in the grid.
-    pub fn sum(&self) -> i64 {
-        self.data.iter().sum()
+            ramp[index as usize]
+        })
     }
+}
 
-    /// Returns an iterator over the positions and integers in the grid.
-    /// The iterator returns the position and integer as a tuple.
-    pub fn iter(&self) -> impl Iterator<Item = (Position, i64)> + '_ {
-        self.data.iter().enumerate().map(move |(i, &value)| {
-            let x = i % self.width;
-            let y = i / self.width;
-            (Position { x, y }, value)
-        })
+impl Display for Grid<i64> {
+    /// Renders the grid with the values of each row separated by spaces and
+    /// right-aligned to the widest value.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.data.iter()
+            .map(|value| value.to_string().len())
+            .max()
+            .unwrap_or(0);
+
+        for y in 0..self.height {
+            let row: Vec<String> = self.row(y).iter()
+                .map(|value| format!("{:>width$}", value, width = width))
+                .collect();
+
+            writeln!(f, "{}", row.join(" "))?;
+        }
+
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::IntGrid;
+    use super::{IntGrid, HEATMAP_RAMP};
     use crate::primitives::Position;
 
     #[test]
@@ -102,7 +87,7 @@ mod test {
 
         grid.set(Position { x: 1, y: 0 }, 42);
         
-        assert_eq!(grid.get(Position { x: 1, y: 0 }), 42);
+        assert_eq!(grid.get(Position { x: 1, y: 0 }), Some(&42));
     }
 
     #[test]
@@ -124,4 +109,25 @@ mod test {
 
         assert_eq!(grid.sum(), 21);
     }
+
+    #[test]
+    fn display_should_align_columns() {
+        let grid = IntGrid::from_vec(3, 2, vec![1, -20, 3, 400, 5, 6]);
+
+        assert_eq!("  1 -20   3\n400   5   6\n", grid.to_string());
+    }
+
+    #[test]
+    fn heatmap_should_map_lowest_and_highest_value_to_ends_of_ramp() {
+        let grid = IntGrid::from_vec(3, 1, vec![0, 5, 9]);
+
+        assert_eq!(" +@\n", grid.heatmap(HEATMAP_RAMP).to_string());
+    }
+
+    #[test]
+    fn heatmap_should_panic_for_empty_ramp() {
+        let grid = IntGrid::new(2, 2, 0);
+
+        assert!(std::panic::catch_unwind(|| grid.heatmap("")).is_err());
+    }
 }
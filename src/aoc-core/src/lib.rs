@@ -1,13 +1,33 @@
+pub mod answer;
+pub mod answer_store;
+pub mod astar;
+pub mod bench;
+pub mod bfs;
 pub mod char_grid;
 pub mod dijkstra;
 pub mod directed_graph;
+pub mod graph;
+pub mod grid;
+pub mod grid_graph;
+pub mod indexed_graph;
+pub mod input_source;
 pub mod int_grid;
+pub mod observer;
 pub mod primitives;
+pub mod puzzle;
+pub mod region;
+pub mod simple_paths;
+pub mod solution;
+#[cfg(test)]
+mod temp_dir;
 pub mod text_map;
+pub mod tsp;
 pub mod undirected_graph;
 
-use std::{io, time::Duration};
+use std::{io, sync::Arc};
 
+use input_source::{AocClient, CacheThenFetch, DiskCache, InputSource};
+use observer::{InputObserver, SilentObserver};
 use thiserror::Error;
 
 /// Errors that can happen for the input for the puzzle.
@@ -26,9 +46,9 @@ pub enum InputError {
 
     /// There was an error while downloading the input for the puzzle.
     #[error(
-        "There was an error while downloading the input for the puzzle of day {1} for year {2}."
+        "There was an error while downloading the input for the puzzle of day {2} for year {1}."
     )]
-    DownloadInput(#[source] ureq::Error, u32, u32),
+    DownloadInput(#[source] Box<ureq::Error>, u32, u32),
 
     /// There was an I/O error while reading the input body.
     #[error("There was an I/O error while reading the input body.")]
@@ -37,6 +57,36 @@ pub enum InputError {
     /// There was an I/O error while creating the data directory.
     #[error("There was an I/O error while creating the data directory '{1}'.")]
     CreateDataDir(#[source] io::Error, String),
+
+    /// The data directory of the user could not be determined.
+    #[error("The data directory of the user could not be determined.")]
+    DataDirNotFound,
+
+    /// The puzzle is not unlocked yet, i. e. adventofcode.com answered with
+    /// 404.
+    #[error("The puzzle of day {1} for year {0} is not unlocked yet.")]
+    PuzzleNotUnlocked(u32, u32),
+
+    /// The session cookie was rejected, i. e. adventofcode.com answered with
+    /// 400.
+    #[error("The session cookie was rejected by adventofcode.com. Please update 'AOC_SESSION_COOKIE' or the 'session.cookie' file.")]
+    InvalidSessionCookie,
+
+    /// adventofcode.com answered with a server error.
+    #[error("The server answered with status {0} for the puzzle of day {2} for year {1}.")]
+    ServerError(u16, u32, u32),
+
+    /// adventofcode.com did not answer in time.
+    #[error("The request for the puzzle of day {1} for year {0} timed out.")]
+    Timeout(u32, u32),
+
+    /// The input source has no input for the puzzle.
+    #[error("There is no input for the puzzle of day {1} for year {0}.")]
+    InputNotFound(u32, u32),
+
+    /// The page of the puzzle has no example with the given number.
+    #[error("There is no example {2} for the puzzle of day {1} for year {0}.")]
+    ExampleNotFound(u32, u32, usize),
 }
 
 const INPUT_FILENAME: &str = r"input.txt";
@@ -47,84 +97,31 @@ const AOC_DATA_SUBDIR: &str = r"aoc";
 /// Otherwise, it downloads the input from adventofcode.com and saves it to the file.
 /// The input file is saved in the data directory of the user.
 ///
+/// Use the types in [`input_source`] to get the input from somewhere else.
+///
 /// # Arguments
 ///
 /// - `year` - The year of the puzzle.
 /// - `day` - The day of the puzzle.
 pub fn get_input(year: u32, day: u32) -> Result<String, InputError> {
-    let data_dir = provision_data_dir(year, day)?;
-    let input_file = data_dir.join(INPUT_FILENAME);
-
-    if input_file.exists() {
-        println!("Reading input from file: {:?}", input_file);
-
-        Ok(std::fs::read_to_string(&input_file)
-            .map_err(|e| InputError::OpenInputFile(e, format!("{:?}", input_file)))?)
-    } else {
-        println!("Downloading input for year {} and day {}...", year, day);
-
-        let input = download_input(year, day)?;
-
-        println!("Saving input to file: {:?}", input_file);
-        
-        std::fs::write(&input_file, &input)
-            .map_err(|e| InputError::OpenInputFile(e, format!("{:?}", input_file)))?;
-
-        Ok(input)
-    }
-}
-
-fn provision_data_dir(year: u32, day: u32) -> Result<std::path::PathBuf, InputError> {
-    let mut data_dir = dirs::data_dir().expect("Data directory not found");
-
-    data_dir.push(AOC_DATA_SUBDIR);
-    data_dir.push(year.to_string());
-    data_dir.push(day.to_string());
-
-    if !data_dir.exists() {
-        std::fs::create_dir_all(&data_dir)
-            .map_err(|e| InputError::CreateDataDir(e, format!("{:?}", data_dir)))?;
-    }
-
-    Ok(data_dir)
-}
-
-fn download_input(year: u32, day: u32) -> Result<String, InputError> {
-    let aoc_input_url = aoc_input_url(year, day);
-    let aoc_session_cookie = aoc_session_cookie()?;
-
-    let agent = ureq::AgentBuilder::new()
-        .timeout_read(Duration::from_secs(5))
-        .timeout_write(Duration::from_secs(5))
-        .build();
-
-    let input_body = agent
-        .get(&aoc_input_url)
-        .set("Cookie", &aoc_session_cookie)
-        .call()
-        .map_err(|e| InputError::DownloadInput(e, year, day))?
-        .into_string()
-        .map_err(InputError::ReadInputBody)?;
-
-    Ok(input_body)
+    get_input_with_observer(year, day, Arc::new(SilentObserver))
 }
 
-fn aoc_input_url(year: u32, day: u32) -> String {
-    format!("https://adventofcode.com/{}/day/{}/input", year, day)
-}
-
-fn aoc_session_cookie() -> Result<String, InputError> {
-    match std::env::var("AOC_SESSION_COOKIE") {
-        Ok(cookie) => Ok(format!("session={}", cookie)),
-        Err(_) => {
-            let mut aoc_session_cookie_filepath = dirs::data_dir().expect("Data directory not found");
-            aoc_session_cookie_filepath.push(AOC_DATA_SUBDIR);
-            aoc_session_cookie_filepath.push("session.cookie");
-
-            match std::fs::read_to_string(&aoc_session_cookie_filepath) {
-                Ok(cookie) => Ok(format!("session={}", cookie.trim())),
-                Err(_) => Err(InputError::AocSessionCookieEnvVarNotSet),
-            }
-        }
-    }
+/// Is getting the input for the puzzle of `year` and `day` like [`get_input`]
+/// and notifies the `observer` about reading, downloading and saving the
+/// input.
+///
+/// # Arguments
+///
+/// - `year` - The year of the puzzle.
+/// - `day` - The day of the puzzle.
+/// - `observer` - The observer to notify.
+pub fn get_input_with_observer(year: u32, day: u32, observer: Arc<dyn InputObserver>)
+    -> Result<String, InputError>
+{
+    CacheThenFetch::new(
+        DiskCache::data_dir()?.with_observer(observer.clone()),
+        AocClient::new().with_observer(observer),
+    )
+    .get_input(year, day)
 }
@@ -0,0 +1,147 @@
+use std::{
+    fmt::{self, Debug, Display},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use serde::Serialize;
+
+/// An event that happens while getting the input or the puzzle page of a
+/// puzzle.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum InputEvent {
+    /// The input is read from a file.
+    ReadingInput { year: u32, day: u32, path: PathBuf },
+
+    /// The input is saved to a file.
+    SavingInput { year: u32, day: u32, path: PathBuf },
+
+    /// The input is downloaded from adventofcode.com.
+    DownloadingInput { year: u32, day: u32 },
+
+    /// The puzzle page is downloaded from adventofcode.com.
+    DownloadingPuzzle { year: u32, day: u32 },
+}
+
+impl Display for InputEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputEvent::ReadingInput { path, .. } =>
+                write!(f, "Reading input from file: {:?}", path),
+            InputEvent::SavingInput { path, .. } =>
+                write!(f, "Saving input to file: {:?}", path),
+            InputEvent::DownloadingInput { year, day } =>
+                write!(f, "Downloading input for year {} and day {}...", year, day),
+            InputEvent::DownloadingPuzzle { year, day } =>
+                write!(f, "Downloading puzzle for year {} and day {}...", year, day),
+        }
+    }
+}
+
+/// Is notified about the events that happen while getting the inputs. The
+/// sources do not print anything on their own.
+pub trait InputObserver: Debug + Send + Sync {
+    /// Is called for every event.
+    ///
+    /// # Arguments
+    ///
+    /// - `event` - The event that happened.
+    fn notify(&self, event: &InputEvent);
+}
+
+/// An observer that ignores all events. This is the default of all sources.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SilentObserver;
+
+impl InputObserver for SilentObserver {
+    fn notify(&self, _event: &InputEvent) {}
+}
+
+/// An observer that prints all events to stderr, so that they do not mix
+/// with the answers on stdout.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PrintObserver;
+
+impl InputObserver for PrintObserver {
+    fn notify(&self, event: &InputEvent) {
+        eprintln!("{}", event);
+    }
+}
+
+/// An observer that prints every event as a line of JSON to stderr.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonObserver;
+
+impl InputObserver for JsonObserver {
+    fn notify(&self, event: &InputEvent) {
+        if let Ok(json) = serde_json::to_string(event) {
+            eprintln!("{}", json);
+        }
+    }
+}
+
+/// An observer that records all events, e. g. for tests.
+#[derive(Debug, Default)]
+pub struct RecordingObserver {
+    events: Mutex<Vec<InputEvent>>,
+}
+
+impl RecordingObserver {
+    /// Creates a new `RecordingObserver` without events.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns all events that were recorded so far.
+    pub fn events(&self) -> Vec<InputEvent> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+impl InputObserver for RecordingObserver {
+    fn notify(&self, event: &InputEvent) {
+        self.events.lock().unwrap().push(event.clone());
+    }
+}
+
+/// Returns the observer that is used, if none is given.
+pub(crate) fn silent() -> Arc<dyn InputObserver> {
+    Arc::new(SilentObserver)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_should_describe_event() {
+        assert_eq!(
+            "Downloading input for year 2024 and day 6...",
+            InputEvent::DownloadingInput { year: 2024, day: 6 }.to_string());
+    }
+
+    #[test]
+    fn serialize_should_tag_event_with_its_name() {
+        let event = InputEvent::SavingInput { year: 2024, day: 6, path: PathBuf::from("input.txt") };
+
+        assert_eq!(
+            r#"{"event":"saving_input","year":2024,"day":6,"path":"input.txt"}"#,
+            serde_json::to_string(&event).unwrap());
+    }
+
+    #[test]
+    fn recording_observer_should_record_events_in_order() {
+        let observer = RecordingObserver::new();
+
+        observer.notify(&InputEvent::DownloadingInput { year: 2024, day: 1 });
+        observer.notify(&InputEvent::DownloadingPuzzle { year: 2024, day: 1 });
+
+        assert_eq!(
+            vec![
+                InputEvent::DownloadingInput { year: 2024, day: 1 },
+                InputEvent::DownloadingPuzzle { year: 2024, day: 1 },
+            ],
+            observer.events());
+    }
+}
@@ -1,5 +1,7 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
 /// The position of something in a 2D grid.
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Position {
     /// The x-coordinate of the position.
     pub x: usize,
@@ -8,8 +10,268 @@ pub struct Position {
     pub y: usize,
 }
 
+impl Position {
+    /// Creates a new `Position` with the given coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// - `x` - The x-coordinate.
+    /// - `y` - The y-coordinate.
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the position that is moved by `offset`, if it is inside of
+    /// `bounds`. Otherwise, `None` is returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `offset` - The offset to move by.
+    /// - `bounds` - The rectangle the position has to stay in.
+    pub fn checked_add(self, offset: Offset, bounds: Rect) -> Option<Position> {
+        let x = self.x.checked_add_signed(offset.dx)?;
+        let y = self.y.checked_add_signed(offset.dy)?;
+        let pos = Position { x, y };
+
+        bounds.contains(pos).then_some(pos)
+    }
+
+    /// Returns the neighbor in `direction`, if it is inside of `bounds`.
+    /// Otherwise, `None` is returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `direction` - The direction to step into.
+    /// - `bounds` - The rectangle the position has to stay in.
+    pub fn checked_step(self, direction: Direction, bounds: Rect) -> Option<Position> {
+        self.checked_add(direction.offset(), bounds)
+    }
+
+    /// Returns the Manhattan distance to `other`, i. e. the number of steps
+    /// when only moving horizontally and vertically.
+    ///
+    /// # Arguments
+    ///
+    /// - `other` - The other position.
+    pub fn manhattan_distance(self, other: Position) -> usize {
+        (other - self).manhattan_length()
+    }
+
+    /// Returns the Chebyshev distance to `other`, i. e. the number of steps
+    /// when moving diagonally as well.
+    ///
+    /// # Arguments
+    ///
+    /// - `other` - The other position.
+    pub fn chebyshev_distance(self, other: Position) -> usize {
+        (other - self).chebyshev_length()
+    }
+}
+
+impl Sub for Position {
+    type Output = Offset;
+
+    /// Returns the offset that moves `rhs` to `self`.
+    fn sub(self, rhs: Position) -> Offset {
+        Offset::new(
+            self.x as isize - rhs.x as isize,
+            self.y as isize - rhs.y as isize)
+    }
+}
+
+/// A signed offset between two positions. The y-axis points down, i. e.
+/// north is `(0, -1)`.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Offset {
+    /// The offset along the x-axis.
+    pub dx: isize,
+
+    /// The offset along the y-axis.
+    pub dy: isize,
+}
+
+impl Offset {
+    /// Creates a new `Offset`.
+    ///
+    /// # Arguments
+    ///
+    /// - `dx` - The offset along the x-axis.
+    /// - `dy` - The offset along the y-axis.
+    pub fn new(dx: isize, dy: isize) -> Self {
+        Self { dx, dy }
+    }
+
+    /// Returns the Manhattan length of the offset, i. e. `|dx| + |dy|`.
+    pub fn manhattan_length(self) -> usize {
+        self.dx.unsigned_abs() + self.dy.unsigned_abs()
+    }
+
+    /// Returns the Chebyshev length of the offset, i. e. `max(|dx|, |dy|)`.
+    pub fn chebyshev_length(self) -> usize {
+        self.dx.unsigned_abs().max(self.dy.unsigned_abs())
+    }
+
+    /// Returns the offset with both components reduced to -1, 0 or 1.
+    pub fn signum(self) -> Self {
+        Self::new(self.dx.signum(), self.dy.signum())
+    }
+}
+
+impl Add for Offset {
+    type Output = Offset;
+
+    fn add(self, rhs: Offset) -> Offset {
+        Offset::new(self.dx + rhs.dx, self.dy + rhs.dy)
+    }
+}
+
+impl Neg for Offset {
+    type Output = Offset;
+
+    fn neg(self) -> Offset {
+        Offset::new(-self.dx, -self.dy)
+    }
+}
+
+impl Mul<isize> for Offset {
+    type Output = Offset;
+
+    fn mul(self, rhs: isize) -> Offset {
+        Offset::new(self.dx * rhs, self.dy * rhs)
+    }
+}
+
+/// A direction in a 2D grid. The y-axis points down, i. e. north is up.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Direction {
+    /// Up, i. e. y - 1.
+    North,
+
+    /// Up and right, i. e. x + 1 and y - 1.
+    NorthEast,
+
+    /// Right, i. e. x + 1.
+    East,
+
+    /// Down and right, i. e. x + 1 and y + 1.
+    SouthEast,
+
+    /// Down, i. e. y + 1.
+    South,
+
+    /// Down and left, i. e. x - 1 and y + 1.
+    SouthWest,
+
+    /// Left, i. e. x - 1.
+    West,
+
+    /// Up and left, i. e. x - 1 and y - 1.
+    NorthWest,
+}
+
+impl Direction {
+    /// The four horizontal and vertical directions, clockwise starting with
+    /// north.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// The four diagonal directions, clockwise starting with north-east.
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::NorthEast,
+        Direction::SouthEast,
+        Direction::SouthWest,
+        Direction::NorthWest,
+    ];
+
+    /// The four directions that read a text forwards, i. e. to the right or
+    /// downwards. Every line of a grid is covered by exactly one of them.
+    pub const FORWARD: [Direction; 4] = [
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+    ];
+
+    /// All eight directions, clockwise starting with north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// Returns the offset of one step into the direction.
+    pub fn offset(self) -> Offset {
+        match self {
+            Direction::North => Offset::new(0, -1),
+            Direction::NorthEast => Offset::new(1, -1),
+            Direction::East => Offset::new(1, 0),
+            Direction::SouthEast => Offset::new(1, 1),
+            Direction::South => Offset::new(0, 1),
+            Direction::SouthWest => Offset::new(-1, 1),
+            Direction::West => Offset::new(-1, 0),
+            Direction::NorthWest => Offset::new(-1, -1),
+        }
+    }
+
+    /// Returns the direction of a single step by `offset`, if `offset` is
+    /// one of the eight neighbors.
+    ///
+    /// # Arguments
+    ///
+    /// - `offset` - The offset of the step.
+    pub fn from_offset(offset: Offset) -> Option<Direction> {
+        Direction::ALL.into_iter().find(|direction| direction.offset() == offset)
+    }
+
+    /// Returns `true` if the direction is diagonal.
+    pub fn is_diagonal(self) -> bool {
+        Direction::DIAGONAL.contains(&self)
+    }
+
+    /// Returns the direction after turning right by 90 degrees.
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// Returns the direction after turning left by 90 degrees.
+    pub fn turn_left(self) -> Direction {
+        self.rotate(6)
+    }
+
+    /// Returns the direction after turning right by 45 degrees.
+    pub fn turn_right_45(self) -> Direction {
+        self.rotate(1)
+    }
+
+    /// Returns the direction after turning left by 45 degrees.
+    pub fn turn_left_45(self) -> Direction {
+        self.rotate(7)
+    }
+
+    /// Returns the opposite direction.
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
+
+    /// Rotates clockwise by `steps` times 45 degrees.
+    fn rotate(self, steps: usize) -> Direction {
+        let index = Direction::ALL.iter().position(|&d| d == self).unwrap();
+
+        Direction::ALL[(index + steps) % Direction::ALL.len()]
+    }
+}
+
 /// A rectangle.
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub struct Rect {
     /// The x-coordinate of the upper left corner.
     pub x1: usize,
@@ -41,4 +303,109 @@ impl Rect {
             y2,
         }
     }
+
+    /// Returns the rectangle that covers a grid of the given size, i. e.
+    /// from `(0, 0)` to `(width - 1, height - 1)`. If `width` or `height` is
+    /// 0, the rectangle is empty, i. e. it contains no positions.
+    ///
+    /// # Arguments
+    ///
+    /// - `width` - The width of the grid.
+    /// - `height` - The height of the grid.
+    pub fn from_size(width: usize, height: usize) -> Self {
+        if width == 0 || height == 0 {
+            return Self::new(1, 1, 0, 0);
+        }
+
+        Self::new(0, 0, width - 1, height - 1)
+    }
+
+    /// Returns `true` if the rectangle contains no positions, i. e. if it is
+    /// not normalized.
+    pub fn is_empty(&self) -> bool {
+        self.x2 < self.x1 || self.y2 < self.y1
+    }
+
+    /// Returns `true` if `pos` is inside of the rectangle. The corners are
+    /// part of the rectangle.
+    ///
+    /// # Arguments
+    ///
+    /// - `pos` - The position to check.
+    pub fn contains(&self, pos: Position) -> bool {
+        (self.x1..=self.x2).contains(&pos.x) && (self.y1..=self.y2).contains(&pos.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_step_should_return_neighbor_inside_bounds() {
+        let bounds = Rect::from_size(3, 3);
+
+        assert_eq!(
+            Some(Position::new(2, 0)),
+            Position::new(1, 1).checked_step(Direction::NorthEast, bounds));
+    }
+
+    #[test]
+    fn checked_step_should_return_none_when_leaving_bounds() {
+        let bounds = Rect::from_size(3, 3);
+
+        assert_eq!(None, Position::new(0, 1).checked_step(Direction::West, bounds));
+        assert_eq!(None, Position::new(1, 0).checked_step(Direction::North, bounds));
+        assert_eq!(None, Position::new(2, 2).checked_step(Direction::SouthEast, bounds));
+    }
+
+    #[test]
+    fn turn_right_should_turn_clockwise() {
+        assert_eq!(Direction::East, Direction::North.turn_right());
+        assert_eq!(Direction::North, Direction::West.turn_right());
+        assert_eq!(Direction::SouthWest, Direction::SouthEast.turn_right());
+    }
+
+    #[test]
+    fn turn_left_should_undo_turn_right() {
+        for direction in Direction::ALL {
+            assert_eq!(direction, direction.turn_right().turn_left());
+            assert_eq!(direction, direction.turn_right_45().turn_left_45());
+        }
+    }
+
+    #[test]
+    fn opposite_should_negate_offset() {
+        for direction in Direction::ALL {
+            assert_eq!(-direction.offset(), direction.opposite().offset());
+        }
+    }
+
+    #[test]
+    fn from_offset_should_only_accept_single_steps() {
+        assert_eq!(Some(Direction::SouthWest), Direction::from_offset(Offset::new(-1, 1)));
+        assert_eq!(None, Direction::from_offset(Offset::new(2, 0)));
+        assert_eq!(None, Direction::from_offset(Offset::default()));
+    }
+
+    #[test]
+    fn distances_should_be_calculated_in_both_metrics() {
+        let a = Position::new(1, 5);
+        let b = Position::new(4, 1);
+
+        assert_eq!(7, a.manhattan_distance(b));
+        assert_eq!(4, a.chebyshev_distance(b));
+        assert_eq!(Offset::new(3, -4), b - a);
+    }
+
+    #[test]
+    fn from_size_should_return_empty_rect_for_empty_grid() {
+        for bounds in [Rect::from_size(0, 0), Rect::from_size(3, 0), Rect::from_size(0, 3)] {
+            assert!(bounds.is_empty());
+            assert!(!bounds.contains(Position::new(0, 0)));
+            assert_eq!(None, Position::new(0, 0).checked_step(Direction::East, bounds));
+        }
+
+        assert!(!Rect::from_size(1, 1).is_empty());
+    }
 }
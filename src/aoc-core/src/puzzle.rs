@@ -0,0 +1,379 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    input_source::{AocClient, DiskCache},
+    InputError,
+};
+
+const PUZZLE_FILENAME: &str = r"puzzle.html";
+const EXAMPLES_SUBDIR: &str = r"examples";
+
+/// The description of a puzzle together with the examples and the example
+/// answers that were extracted from its HTML page.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Puzzle {
+    html: String,
+    examples: Vec<String>,
+    answers: Vec<Option<String>>,
+}
+
+impl Puzzle {
+    /// Creates a new `Puzzle` from the HTML page of the puzzle. The examples
+    /// are the `<pre><code>` blocks of the page. The example answer of a part
+    /// is the last `<code><em>` in the description of the part.
+    ///
+    /// # Arguments
+    ///
+    /// - `html` - The HTML page of the puzzle.
+    pub fn from_html(html: &str) -> Self {
+        Self {
+            html: html.to_string(),
+            examples: extract_examples(html),
+            answers: extract_answers(html),
+        }
+    }
+
+    /// Returns the HTML page of the puzzle.
+    pub fn html(&self) -> &str {
+        &self.html
+    }
+
+    /// Returns all examples of the puzzle in the order of the page.
+    pub fn examples(&self) -> &[String] {
+        &self.examples
+    }
+
+    /// Returns the example `n`. The examples are numbered starting with 1.
+    ///
+    /// # Arguments
+    ///
+    /// - `n` - The number of the example.
+    pub fn example(&self, n: usize) -> Option<&str> {
+        n.checked_sub(1)
+            .and_then(|index| self.examples.get(index))
+            .map(|example| example.as_str())
+    }
+
+    /// Returns the number of parts that are described on the page. The second
+    /// part is only visible after the first part has been solved.
+    pub fn parts(&self) -> usize {
+        self.answers.len()
+    }
+
+    /// Returns the example answer that is stated for `part`, if there is one.
+    ///
+    /// # Arguments
+    ///
+    /// - `part` - The part of the puzzle, i. e. 1 or 2.
+    pub fn answer(&self, part: u32) -> Option<&str> {
+        (part as usize).checked_sub(1)
+            .and_then(|index| self.answers.get(index))
+            .and_then(|answer| answer.as_deref())
+    }
+}
+
+/// Gets the puzzle descriptions and stores them in the on-disk cache next to
+/// the input. For a puzzle the cache contains:
+///
+/// - `puzzle.html` - The HTML page of the puzzle.
+/// - `examples/<n>.txt` - The examples, numbered starting with 1.
+/// - `examples/answer_<part>.txt` - The example answer of a part.
+#[derive(Debug, Clone)]
+pub struct PuzzleStore {
+    cache: DiskCache,
+    client: AocClient,
+}
+
+impl PuzzleStore {
+    /// Creates a new `PuzzleStore`.
+    ///
+    /// # Arguments
+    ///
+    /// - `cache` - The cache to store the puzzles in.
+    /// - `client` - The client to download the puzzles with.
+    pub fn new(cache: DiskCache, client: AocClient) -> Self {
+        Self { cache, client }
+    }
+
+    /// Creates a new `PuzzleStore` that uses the data directory of the user
+    /// and adventofcode.com.
+    pub fn data_dir() -> Result<Self, InputError> {
+        Ok(Self::new(DiskCache::data_dir()?, AocClient::new()))
+    }
+
+    /// Returns the puzzle of `year` and `day`. The puzzle is read from the
+    /// cache, if it was downloaded before. Otherwise, it is downloaded and
+    /// stored in the cache.
+    ///
+    /// # Arguments
+    ///
+    /// - `year` - The year of the puzzle.
+    /// - `day` - The day of the puzzle.
+    pub fn get_puzzle(&self, year: u32, day: u32) -> Result<Puzzle, InputError> {
+        let puzzle_file = self.puzzle_file(year, day);
+
+        if puzzle_file.exists() {
+            let html = std::fs::read_to_string(&puzzle_file)
+                .map_err(|e| InputError::OpenInputFile(e, format!("{:?}", puzzle_file)))?;
+
+            Ok(Puzzle::from_html(&html))
+        } else {
+            self.refresh_puzzle(year, day)
+        }
+    }
+
+    /// Downloads the puzzle of `year` and `day` and replaces the cached one.
+    /// This is needed to get the second part, after the first part has been
+    /// solved.
+    ///
+    /// # Arguments
+    ///
+    /// - `year` - The year of the puzzle.
+    /// - `day` - The day of the puzzle.
+    pub fn refresh_puzzle(&self, year: u32, day: u32) -> Result<Puzzle, InputError> {
+        let puzzle = Puzzle::from_html(&self.client.get_puzzle_page(year, day)?);
+
+        self.save_puzzle(year, day, &puzzle)?;
+
+        Ok(puzzle)
+    }
+
+    /// Returns the example `n` of the puzzle of `year` and `day`. The examples
+    /// are numbered starting with 1.
+    ///
+    /// # Arguments
+    ///
+    /// - `year` - The year of the puzzle.
+    /// - `day` - The day of the puzzle.
+    /// - `n` - The number of the example.
+    pub fn get_example(&self, year: u32, day: u32, n: usize) -> Result<String, InputError> {
+        self.get_puzzle(year, day)?
+            .example(n)
+            .map(|example| example.to_string())
+            .ok_or(InputError::ExampleNotFound(year, day, n))
+    }
+
+    /// Returns the example answer of `part` of the puzzle of `year` and `day`,
+    /// if the page states one.
+    ///
+    /// # Arguments
+    ///
+    /// - `year` - The year of the puzzle.
+    /// - `day` - The day of the puzzle.
+    /// - `part` - The part of the puzzle, i. e. 1 or 2.
+    pub fn get_example_answer(&self, year: u32, day: u32, part: u32)
+        -> Result<Option<String>, InputError>
+    {
+        Ok(self.get_puzzle(year, day)?.answer(part).map(|answer| answer.to_string()))
+    }
+
+    fn puzzle_file(&self, year: u32, day: u32) -> PathBuf {
+        self.cache.puzzle_dir(year, day).join(PUZZLE_FILENAME)
+    }
+
+    fn save_puzzle(&self, year: u32, day: u32, puzzle: &Puzzle) -> Result<(), InputError> {
+        let puzzle_dir = self.cache.provision_puzzle_dir(year, day)?;
+        let examples_dir = puzzle_dir.join(EXAMPLES_SUBDIR);
+
+        write_file(&puzzle_dir.join(PUZZLE_FILENAME), puzzle.html())?;
+
+        // remove the examples of an older version of the page
+        if examples_dir.exists() {
+            std::fs::remove_dir_all(&examples_dir)
+                .map_err(|e| InputError::CreateDataDir(e, format!("{:?}", examples_dir)))?;
+        }
+
+        std::fs::create_dir_all(&examples_dir)
+            .map_err(|e| InputError::CreateDataDir(e, format!("{:?}", examples_dir)))?;
+
+        for (index, example) in puzzle.examples().iter().enumerate() {
+            write_file(&examples_dir.join(format!("{}.txt", index + 1)), example)?;
+        }
+
+        for part in 1..=puzzle.parts() as u32 {
+            if let Some(answer) = puzzle.answer(part) {
+                write_file(&examples_dir.join(format!("answer_{}.txt", part)), answer)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the example `n` of the puzzle of `year` and `day` from the data
+/// directory of the user. The puzzle is downloaded, if it is not cached yet.
+///
+/// # Arguments
+///
+/// - `year` - The year of the puzzle.
+/// - `day` - The day of the puzzle.
+/// - `n` - The number of the example, starting with 1.
+pub fn get_example(year: u32, day: u32, n: usize) -> Result<String, InputError> {
+    PuzzleStore::data_dir()?.get_example(year, day, n)
+}
+
+/// Returns the example answer of `part` of the puzzle of `year` and `day` from
+/// the data directory of the user. The puzzle is downloaded, if it is not
+/// cached yet.
+///
+/// # Arguments
+///
+/// - `year` - The year of the puzzle.
+/// - `day` - The day of the puzzle.
+/// - `part` - The part of the puzzle, i. e. 1 or 2.
+pub fn get_example_answer(year: u32, day: u32, part: u32) -> Result<Option<String>, InputError> {
+    PuzzleStore::data_dir()?.get_example_answer(year, day, part)
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), InputError> {
+    std::fs::write(path, contents)
+        .map_err(|e| InputError::OpenInputFile(e, format!("{:?}", path)))
+}
+
+/// Returns the contents of all `<pre><code>` blocks.
+fn extract_examples(html: &str) -> Vec<String> {
+    find_all_between(html, "<pre><code>", "</code></pre>")
+        .into_iter()
+        .map(|(_, block)| unescape(&strip_tags(block)))
+        .collect()
+}
+
+/// Returns the last `<code><em>` of every `<article>`, i. e. of every part.
+fn extract_answers(html: &str) -> Vec<Option<String>> {
+    find_all_between(html, "<article", "</article>")
+        .into_iter()
+        .map(|(_, article)| {
+            // the examples can contain emphasized code as well
+            let mut text = String::new();
+            let mut pos = 0;
+
+            for (offset, block) in find_all_between(article, "<pre>", "</pre>") {
+                text.push_str(&article[pos..offset]);
+                pos = offset + block.len();
+            }
+
+            text.push_str(&article[pos..]);
+
+            let code_em = find_all_between(&text, "<code><em>", "</em></code>");
+            let em_code = find_all_between(&text, "<em><code>", "</code></em>");
+
+            let last = code_em.last().into_iter()
+                .chain(em_code.last())
+                .max_by_key(|(offset, _)| *offset);
+
+            last.map(|(_, answer)| unescape(&strip_tags(answer)))
+        })
+        .collect()
+}
+
+/// Returns all parts of `s` that are between `start` and `end` together with
+/// their byte offset in `s`.
+fn find_all_between<'a>(s: &'a str, start: &str, end: &str) -> Vec<(usize, &'a str)> {
+    let mut result = vec![];
+    let mut pos = 0;
+
+    while let Some(start_pos) = s[pos..].find(start) {
+        let content = pos + start_pos + start.len();
+
+        match s[content..].find(end) {
+            Some(end_pos) => {
+                result.push((content, &s[content..content + end_pos]));
+                pos = content + end_pos + end.len();
+            }
+            None => break,
+        }
+    }
+
+    result
+}
+
+/// Removes all HTML tags from `s`.
+fn strip_tags(s: &str) -> String {
+    let mut result = String::new();
+    let mut in_tag = false;
+
+    for c in s.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => result.push(c),
+            _ => {}
+        }
+    }
+
+    result
+}
+
+/// Replaces the HTML entities that are used on adventofcode.com.
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_tags_should_remove_em() {
+        assert_eq!("a b c", strip_tags("a <em>b</em> c"));
+    }
+
+    #[test]
+    fn unescape_should_replace_entities() {
+        assert_eq!("<a> & \"b\"", unescape("&lt;a&gt; &amp; &quot;b&quot;"));
+    }
+
+    #[test]
+    fn unescape_should_not_unescape_twice() {
+        assert_eq!("&lt;", unescape("&amp;lt;"));
+    }
+
+    #[test]
+    fn find_all_between_should_return_all_parts() {
+        assert_eq!(
+            vec![(3, "1"), (11, "2")],
+            find_all_between("<a>1</a><a>2</a><a>3", "<a>", "</a>"));
+    }
+
+    #[test]
+    fn extract_examples_should_return_unescaped_blocks() {
+        let html = "<p>x</p><pre><code>a&lt;b\n<em>c</em>\n</code></pre>";
+
+        assert_eq!(vec!["a<b\nc\n".to_string()], extract_examples(html));
+    }
+
+    #[test]
+    fn extract_answers_should_return_last_emphasized_code_per_article() {
+        let html = r#"<article class="day-desc"><code><em>1</em></code> <code><em>2</em></code></article>
+<article class="day-desc"><em><code>3</code></em><pre><code><em>4</em></code></pre></article>"#;
+
+        assert_eq!(
+            vec![Some("2".to_string()), Some("3".to_string())],
+            extract_answers(html));
+    }
+
+    #[test]
+    fn extract_answers_should_use_position_instead_of_content() {
+        // the answer 9 appears in the prose again and the example repeats
+        // the emphasized code of the prose
+        let html = r#"<article><code><em>1</em></code> <em><code>9</code></em> of 9
+<pre><code><em>1</em></code></pre></article>"#;
+
+        assert_eq!(vec![Some("9".to_string())], extract_answers(html));
+    }
+
+    #[test]
+    fn example_should_be_numbered_starting_with_1() {
+        let puzzle = Puzzle::from_html("<pre><code>first</code></pre><pre><code>second</code></pre>");
+
+        assert_eq!(None, puzzle.example(0));
+        assert_eq!(Some("first"), puzzle.example(1));
+        assert_eq!(Some("second"), puzzle.example(2));
+        assert_eq!(None, puzzle.example(3));
+    }
+}
@@ -0,0 +1,276 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use crate::{
+    grid::Grid,
+    int_grid::IntGrid,
+    primitives::{Direction, Position, Rect},
+};
+
+/// Defines which cells are neighbors when regions are searched.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Connectivity {
+    /// Only horizontal and vertical neighbors are connected.
+    Four,
+
+    /// Horizontal, vertical and diagonal neighbors are connected.
+    Eight,
+}
+
+/// A set of connected cells of a grid.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Region {
+    cells: BTreeSet<Position>,
+}
+
+impl Region {
+    /// Returns the cells of the region.
+    pub fn cells(&self) -> &BTreeSet<Position> {
+        &self.cells
+    }
+
+    /// Returns `true` if `pos` is part of the region.
+    ///
+    /// # Arguments
+    ///
+    /// - `pos` - The position to check.
+    pub fn contains(&self, pos: Position) -> bool {
+        self.cells.contains(&pos)
+    }
+
+    /// Returns the number of cells of the region.
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns the number of cell edges that separate the region from cells
+    /// outside of it, including the edges of holes. Only the four sides of a
+    /// cell are edges, even for a region of [`Connectivity::Eight`].
+    pub fn perimeter(&self) -> usize {
+        self.cells.iter()
+            .map(|&pos| {
+                Direction::CARDINAL.iter()
+                    .filter(|&&direction| !self.contains_neighbor(pos, direction))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Returns the smallest rectangle that contains all cells of the region
+    /// or `None`, if the region is empty.
+    pub fn bounds(&self) -> Option<Rect> {
+        let first = self.cells.first()?;
+
+        let (x1, y1, x2, y2) = self.cells.iter().fold(
+            (first.x, first.y, first.x, first.y),
+            |(x1, y1, x2, y2), pos| (x1.min(pos.x), y1.min(pos.y), x2.max(pos.x), y2.max(pos.y)));
+
+        Some(Rect::new(x1, y1, x2, y2))
+    }
+
+    /// Returns the number of corners of the outline of the region, including
+    /// the corners of holes. Cells that only touch diagonally are treated as
+    /// separate squares, i. e. the outline does not depend on the
+    /// connectivity that was used to find the region. For a region of
+    /// [`Connectivity::Eight`], the point where two such cells touch is
+    /// counted as a corner of both cells.
+    pub fn corners(&self) -> usize {
+        self.cells.iter()
+            .map(|&pos| {
+                Direction::CARDINAL.iter()
+                    .filter(|&&direction| {
+                        let side = direction.turn_right();
+                        let straight = self.contains_neighbor(pos, direction);
+                        let beside = self.contains_neighbor(pos, side);
+                        let diagonal = self.contains_neighbor(pos, direction.turn_right_45());
+
+                        // convex corner or concave corner
+                        (!straight && !beside) || (straight && beside && !diagonal)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Returns the number of straight sides of the outline of the region,
+    /// including the sides of holes. It is the same as the number of corners,
+    /// so cells that only touch diagonally are treated as separate squares as
+    /// well.
+    pub fn sides(&self) -> usize {
+        self.corners()
+    }
+
+    fn contains_neighbor(&self, pos: Position, direction: Direction) -> bool {
+        let offset = direction.offset();
+
+        match (pos.x.checked_add_signed(offset.dx), pos.y.checked_add_signed(offset.dy)) {
+            (Some(x), Some(y)) => self.contains(Position::new(x, y)),
+            _ => false,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Returns the region of all cells that are reachable from `start` and
+    /// match the `predicate`. The region is empty if `start` is outside of
+    /// the grid or does not match the `predicate`.
+    ///
+    /// # Arguments
+    ///
+    /// - `start` - The position to start at.
+    /// - `connectivity` - Defines which cells are neighbors.
+    /// - `predicate` - The predicate the value of a cell has to match.
+    pub fn flood_fill(
+        &self,
+        start: Position,
+        connectivity: Connectivity,
+        predicate: impl Fn(&T) -> bool,
+    ) -> Region {
+        let mut region = Region::default();
+
+        if !self.get(start).is_some_and(&predicate) {
+            return region;
+        }
+
+        let mut queue: VecDeque<Position> = VecDeque::from([start]);
+        region.cells.insert(start);
+
+        while let Some(pos) = queue.pop_front() {
+            let neighbors: Vec<Position> = match connectivity {
+                Connectivity::Four => self.neighbors4(pos).collect(),
+                Connectivity::Eight => self.neighbors8(pos).collect(),
+            };
+
+            for neighbor in neighbors {
+                if predicate(&self[neighbor]) && region.cells.insert(neighbor) {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        region
+    }
+
+    /// Splits the grid into regions of connected cells, where `eq` is `true`
+    /// for the values of the cells. Returns an `IntGrid` with the label of the
+    /// region of every cell and the regions, where the label is the index of
+    /// the region. The regions are labelled row by row.
+    ///
+    /// # Arguments
+    ///
+    /// - `connectivity` - Defines which cells are neighbors.
+    /// - `eq` - Returns `true` if two values belong to the same region.
+    pub fn connected_components(
+        &self,
+        connectivity: Connectivity,
+        eq: impl Fn(&T, &T) -> bool,
+    ) -> (IntGrid, Vec<Region>) {
+        let mut labels = IntGrid::new(self.width(), self.height(), -1);
+        let mut regions: Vec<Region> = vec![];
+
+        for (start, value) in self.iter() {
+            if labels[start] >= 0 {
+                continue;
+            }
+
+            let region = self.flood_fill(start, connectivity, |other| eq(value, other));
+
+            for &pos in region.cells() {
+                labels[pos] = regions.len() as i64;
+            }
+
+            regions.push(region);
+        }
+
+        (labels, regions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text_map::TextMap;
+
+    const EXAMPLE_GARDEN: &str = r"AAAA
+BBCD
+BBCC
+EEEC";
+
+    #[test]
+    fn flood_fill_should_return_connected_cells() {
+        let garden = TextMap::try_from(EXAMPLE_GARDEN).unwrap();
+
+        let region = garden.flood_fill(Position::new(2, 1), Connectivity::Four, |&c| c == 'C');
+
+        assert_eq!(4, region.area());
+        assert_eq!(Some(Rect::new(2, 1, 3, 3)), region.bounds());
+    }
+
+    #[test]
+    fn flood_fill_should_return_empty_region_when_start_does_not_match() {
+        let garden = TextMap::try_from(EXAMPLE_GARDEN).unwrap();
+
+        let region = garden.flood_fill(Position::new(0, 0), Connectivity::Four, |&c| c == 'C');
+
+        assert_eq!(0, region.area());
+        assert_eq!(None, region.bounds());
+    }
+
+    #[test]
+    fn connected_components_should_label_every_region() {
+        let garden = TextMap::try_from(EXAMPLE_GARDEN).unwrap();
+
+        let (labels, regions) = garden.connected_components(Connectivity::Four, |a, b| a == b);
+
+        assert_eq!(5, regions.len());
+        assert_eq!(
+            IntGrid::from_vec(4, 4, vec![0, 0, 0, 0, 1, 1, 2, 3, 1, 1, 2, 2, 4, 4, 4, 2]),
+            labels);
+    }
+
+    #[test]
+    fn regions_should_report_area_perimeter_and_sides() {
+        let garden = TextMap::try_from(EXAMPLE_GARDEN).unwrap();
+
+        let (_, regions) = garden.connected_components(Connectivity::Four, |a, b| a == b);
+        let reports: Vec<(usize, usize, usize)> = regions.iter()
+            .map(|region| (region.area(), region.perimeter(), region.sides()))
+            .collect();
+
+        assert_eq!(vec![(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)], reports);
+    }
+
+    #[test]
+    fn sides_should_count_sides_of_holes() {
+        let garden = TextMap::try_from("OOO\nOXO\nOOO").unwrap();
+
+        let region = garden.flood_fill(Position::new(0, 0), Connectivity::Four, |&c| c == 'O');
+
+        assert_eq!(16, region.perimeter());
+        assert_eq!(8, region.sides());
+    }
+
+    #[test]
+    fn connected_components_should_connect_diagonals_with_eight_connectivity() {
+        let garden = TextMap::try_from("X.\n.X").unwrap();
+
+        let (_, four) = garden.connected_components(Connectivity::Four, |a, b| a == b);
+        let (_, eight) = garden.connected_components(Connectivity::Eight, |a, b| a == b);
+
+        assert_eq!(4, four.len());
+        assert_eq!(2, eight.len());
+        assert_eq!(2, eight[0].area());
+        assert_eq!(8, eight[0].corners());
+    }
+
+    #[test]
+    fn sides_should_treat_diagonal_cells_as_separate_squares_with_eight_connectivity() {
+        // two L-shaped regions with four connectivity, one ring with eight
+        let garden = TextMap::try_from("XX.\nX.X\n.XX").unwrap();
+
+        let region = garden.flood_fill(Position::new(0, 0), Connectivity::Eight, |&c| c == 'X');
+
+        assert_eq!(6, region.area());
+        assert_eq!(16, region.perimeter());
+        assert_eq!(12, region.sides());
+    }
+}
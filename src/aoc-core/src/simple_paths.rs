@@ -0,0 +1,254 @@
+use std::{borrow::Borrow, collections::HashSet, vec::IntoIter};
+
+use crate::graph::Graph;
+
+type Prune<'a, N, W> = Box<dyn Fn(&[N], W) -> bool + 'a>;
+
+/// An iterator over all simple paths, i. e. paths that visit no node twice,
+/// that start at a node. The paths are yielded in depth-first order together
+/// with their cost. The path that only contains the start node is yielded as
+/// well.
+///
+/// The search can be restricted to paths that end at a goal with
+/// [`with_goal`](SimplePaths::with_goal) and cut short with
+/// [`with_prune`](SimplePaths::with_prune).
+pub struct SimplePaths<'a, G: Graph> {
+    graph: &'a G,
+    goal: Option<G::Node>,
+    prune: Option<Prune<'a, G::Node, G::Weight>>,
+    path: Vec<G::Node>,
+    costs: Vec<G::Weight>,
+    visited: HashSet<G::Node>,
+    stack: Vec<IntoIter<(G::Node, G::Weight)>>,
+    started: bool,
+}
+
+impl<'a, G: Graph> SimplePaths<'a, G> {
+    /// Creates a new `SimplePaths` iterator.
+    ///
+    /// # Arguments
+    ///
+    /// - `graph` - The graph to search.
+    /// - `start` - The node all paths start at.
+    pub fn new<Q>(graph: &'a G, start: &Q) -> Self
+    where
+        G::Node: Borrow<Q>,
+        Q: ToOwned<Owned = G::Node> + ?Sized,
+    {
+        Self {
+            graph,
+            goal: None,
+            prune: None,
+            path: vec![start.to_owned()],
+            costs: vec![G::Weight::default()],
+            visited: HashSet::new(),
+            stack: vec![],
+            started: false,
+        }
+    }
+
+    /// Only yields the paths that end at `goal`. Paths are not continued
+    /// past the goal.
+    ///
+    /// # Arguments
+    ///
+    /// - `goal` - The node all paths end at.
+    pub fn with_goal<Q>(mut self, goal: &Q) -> Self
+    where
+        G::Node: Borrow<Q>,
+        Q: ToOwned<Owned = G::Node> + ?Sized,
+    {
+        self.goal = Some(goal.to_owned());
+        self
+    }
+
+    /// Sets a closure that is called with every new path and its cost. If it
+    /// returns `true`, the path is neither yielded nor continued.
+    ///
+    /// # Arguments
+    ///
+    /// - `prune` - Returns `true` for paths that should be skipped.
+    pub fn with_prune(mut self, prune: impl Fn(&[G::Node], G::Weight) -> bool + 'a) -> Self {
+        self.prune = Some(Box::new(prune));
+        self
+    }
+
+    fn cost(&self) -> G::Weight {
+        self.costs[self.costs.len() - 1]
+    }
+
+    fn is_goal(&self, node: &G::Node) -> bool {
+        self.goal.as_ref().is_none_or(|goal| goal == node)
+    }
+
+    fn is_pruned(&self) -> bool {
+        self.prune.as_ref().is_some_and(|prune| prune(&self.path, self.cost()))
+    }
+
+    // adds the last node of the path to the search, returns false if the
+    // path does not have to be continued
+    fn enter(&mut self) -> bool {
+        let node = self.path[self.path.len() - 1].clone();
+
+        if self.goal.as_ref().is_some_and(|goal| *goal == node) {
+            return false;
+        }
+
+        self.stack.push(self.graph.neighbors(&node).into_iter());
+        self.visited.insert(node);
+
+        true
+    }
+
+    fn leave(&mut self) {
+        if let Some(node) = self.path.pop() {
+            self.visited.remove(&node);
+        }
+
+        self.costs.pop();
+    }
+}
+
+impl<G: Graph> Iterator for SimplePaths<'_, G> {
+    type Item = (Vec<G::Node>, G::Weight);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+
+            if self.is_pruned() {
+                return None;
+            }
+
+            let start = self.path[0].clone();
+            let result = self.is_goal(&start).then(|| (self.path.clone(), self.cost()));
+
+            self.enter();
+
+            if result.is_some() {
+                return result;
+            }
+        }
+
+        loop {
+            let Some((node, weight)) = self.stack.last_mut()?.next() else {
+                // all neighbors of the last node are done
+                self.stack.pop();
+                self.leave();
+                continue;
+            };
+
+            if self.visited.contains(&node) {
+                continue;
+            }
+
+            let cost = self.cost() + weight;
+
+            self.path.push(node.clone());
+            self.costs.push(cost);
+
+            if self.is_pruned() {
+                self.leave();
+                continue;
+            }
+
+            let result = self.is_goal(&node).then(|| (self.path.clone(), cost));
+
+            if !self.enter() {
+                self.leave();
+            }
+
+            if result.is_some() {
+                return result;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{directed_graph::DirectedGraph, undirected_graph::UndirectedGraph};
+
+    fn diamond() -> UndirectedGraph {
+        let mut graph = UndirectedGraph::new();
+
+        graph.add_edge("A", "B", 1);
+        graph.add_edge("A", "C", 2);
+        graph.add_edge("B", "D", 3);
+        graph.add_edge("C", "D", 4);
+
+        graph
+    }
+
+    fn sorted(paths: impl Iterator<Item = (Vec<String>, i32)>) -> Vec<(Vec<String>, i32)> {
+        let mut paths: Vec<(Vec<String>, i32)> = paths.collect();
+
+        paths.sort();
+
+        paths
+    }
+
+    fn path(nodes: &[&str], cost: i32) -> (Vec<String>, i32) {
+        (nodes.iter().map(|node| node.to_string()).collect(), cost)
+    }
+
+    #[test]
+    fn simple_paths_should_yield_all_paths_between_nodes() {
+        let graph = diamond();
+
+        assert_eq!(
+            sorted(SimplePaths::new(&graph, "A").with_goal("D")),
+            vec![path(&["A", "B", "D"], 4), path(&["A", "C", "D"], 6)]);
+    }
+
+    #[test]
+    fn simple_paths_should_yield_all_paths_from_start() {
+        let graph = diamond();
+
+        assert_eq!(
+            sorted(SimplePaths::new(&graph, "A")),
+            vec![
+                path(&["A"], 0),
+                path(&["A", "B"], 1),
+                path(&["A", "B", "D"], 4),
+                path(&["A", "B", "D", "C"], 8),
+                path(&["A", "C"], 2),
+                path(&["A", "C", "D"], 6),
+                path(&["A", "C", "D", "B"], 9),
+            ]);
+    }
+
+    #[test]
+    fn simple_paths_should_skip_pruned_paths() {
+        let graph = diamond();
+
+        let paths = SimplePaths::new(&graph, "A")
+            .with_goal("D")
+            .with_prune(|_, cost| cost > 5);
+
+        assert_eq!(sorted(paths), vec![path(&["A", "B", "D"], 4)]);
+    }
+
+    #[test]
+    fn simple_paths_should_yield_start_if_it_is_the_goal() {
+        let graph = diamond();
+
+        assert_eq!(
+            sorted(SimplePaths::new(&graph, "A").with_goal("A")),
+            vec![path(&["A"], 0)]);
+    }
+
+    #[test]
+    fn simple_paths_should_follow_edge_direction_in_directed_graph() {
+        let mut graph = DirectedGraph::new();
+
+        graph.add_edge("A", "B", 1);
+        graph.add_edge("B", "C", 1);
+        graph.add_edge("C", "A", 1);
+
+        assert_eq!(
+            sorted(SimplePaths::new(&graph, "B").with_goal("A")),
+            vec![path(&["B", "C", "A"], 2)]);
+    }
+}
@@ -0,0 +1,248 @@
+use std::time::{Duration, Instant};
+
+use thiserror::Error;
+
+use crate::bench::{self, BenchOptions, BenchReport};
+
+/// Errors that can happen while running a solution.
+#[derive(Error, Debug, Clone, Eq, PartialEq)]
+pub enum SolutionError {
+    /// The puzzle input could not be parsed.
+    #[error("The puzzle input is invalid: {0}")]
+    InvalidInput(String),
+
+    /// A part other than 1 or 2 was requested.
+    #[error("A puzzle only has the parts 1 and 2, but part {0} was requested.")]
+    InvalidPart(u32),
+}
+
+/// The solution of a puzzle. The puzzle input is parsed once and is then
+/// shared by both parts.
+pub trait Solution {
+    /// The year of the puzzle.
+    const YEAR: u32;
+
+    /// The day of the puzzle.
+    const DAY: u32;
+
+    /// The parsed puzzle input.
+    type Input;
+
+    /// Parses the puzzle input. Returns [`SolutionError::InvalidInput`], if
+    /// the puzzle input is malformed.
+    ///
+    /// # Arguments
+    ///
+    /// - `puzzle_input` - The puzzle input as it was downloaded.
+    fn parse(&self, puzzle_input: &str) -> Result<Self::Input, SolutionError>;
+
+    /// Solves the first part of the puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// - `input` - The parsed puzzle input.
+    fn part1(&self, input: &Self::Input) -> String;
+
+    /// Solves the second part of the puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// - `input` - The parsed puzzle input.
+    fn part2(&self, input: &Self::Input) -> String;
+
+    /// Returns alternative implementations of the parts, e. g. another
+    /// algorithm, that are benchmarked together with the parts.
+    fn variants(&self) -> Vec<Variant<Self>> {
+        vec![]
+    }
+}
+
+/// An alternative implementation of a part of a [`Solution`].
+pub struct Variant<S: Solution + ?Sized> {
+    /// The name of the variant, e. g. `recursive`.
+    pub name: &'static str,
+
+    /// The part that is solved by the variant, i. e. 1 or 2.
+    pub part: u32,
+
+    /// Solves the part with the parsed puzzle input.
+    pub solve: fn(&S, &S::Input) -> String,
+}
+
+/// The answer for a part of a puzzle together with the time it took to solve.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PartResult {
+    /// The part of the puzzle, i. e. 1 or 2.
+    pub part: u32,
+
+    /// The answer for the part.
+    pub answer: String,
+
+    /// The time it took to solve the part.
+    pub time: Duration,
+}
+
+/// The result of running a solution on a puzzle input.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SolverRun {
+    /// The time it took to parse the puzzle input.
+    pub parse_time: Duration,
+
+    /// The results of the parts that were run.
+    pub results: Vec<PartResult>,
+}
+
+/// A type-erased [`Solution`], so that solutions with different inputs can be
+/// kept in one list. Every `Solution` is a `Solver`.
+pub trait Solver {
+    /// Returns the year of the puzzle.
+    fn year(&self) -> u32;
+
+    /// Returns the day of the puzzle.
+    fn day(&self) -> u32;
+
+    /// Parses the `puzzle_input` and runs the given `parts` on it. Returns an
+    /// error, if the puzzle input is invalid or a part is neither 1 nor 2.
+    ///
+    /// # Arguments
+    ///
+    /// - `puzzle_input` - The puzzle input.
+    /// - `parts` - The parts to run, i. e. 1 and/or 2.
+    fn run(&self, puzzle_input: &str, parts: &[u32]) -> Result<SolverRun, SolutionError>;
+
+    /// Benchmarks parsing, both parts and all variants on the `puzzle_input`.
+    /// Returns an error, if the puzzle input is invalid.
+    ///
+    /// # Arguments
+    ///
+    /// - `puzzle_input` - The puzzle input.
+    /// - `options` - The number of runs and warmup runs.
+    fn bench(&self, puzzle_input: &str, options: &BenchOptions)
+        -> Result<BenchReport, SolutionError>;
+}
+
+impl<S: Solution> Solver for S {
+    fn year(&self) -> u32 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn run(&self, puzzle_input: &str, parts: &[u32]) -> Result<SolverRun, SolutionError> {
+        let start = Instant::now();
+        let input = self.parse(puzzle_input)?;
+        let parse_time = start.elapsed();
+
+        let results = parts.iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = solve_part(self, &input, part)?;
+
+                Ok(PartResult {
+                    part,
+                    answer,
+                    time: start.elapsed(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(SolverRun {
+            parse_time,
+            results,
+        })
+    }
+
+    fn bench(&self, puzzle_input: &str, options: &BenchOptions)
+        -> Result<BenchReport, SolutionError>
+    {
+        bench::bench(self, puzzle_input, options)
+    }
+}
+
+/// Solves `part` of a puzzle with the given `solution`.
+///
+/// # Arguments
+///
+/// - `solution` - The solution of the puzzle.
+/// - `input` - The parsed puzzle input.
+/// - `part` - The part to solve, i. e. 1 or 2.
+///
+/// Returns [`SolutionError::InvalidPart`], if `part` is neither 1 nor 2.
+pub fn solve_part<S: Solution>(solution: &S, input: &S::Input, part: u32)
+    -> Result<String, SolutionError>
+{
+    match part {
+        1 => Ok(solution.part1(input)),
+        2 => Ok(solution.part2(input)),
+        _ => Err(SolutionError::InvalidPart(part)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u32 = 2024;
+        const DAY: u32 = 0;
+
+        type Input = Vec<i32>;
+
+        fn parse(&self, puzzle_input: &str) -> Result<Self::Input, SolutionError> {
+            puzzle_input.lines()
+                .map(|line| line.parse().map_err(|_| SolutionError::InvalidInput(line.to_string())))
+                .collect()
+        }
+
+        fn part1(&self, input: &Self::Input) -> String {
+            input.iter().sum::<i32>().to_string()
+        }
+
+        fn part2(&self, input: &Self::Input) -> String {
+            input.iter().product::<i32>().to_string()
+        }
+    }
+
+    #[test]
+    fn run_should_run_both_parts() {
+        let run = Sum.run("2\n3\n4", &[1, 2]).unwrap();
+
+        assert_eq!(
+            vec![(1, "9".to_string()), (2, "24".to_string())],
+            run.results.into_iter().map(|r| (r.part, r.answer)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn run_should_only_run_requested_part() {
+        let run = Sum.run("2\n3\n4", &[2]).unwrap();
+
+        assert_eq!(1, run.results.len());
+        assert_eq!("24", run.results[0].answer);
+    }
+
+    #[test]
+    fn solver_should_return_year_and_day() {
+        let solver: &dyn Solver = &Sum;
+
+        assert_eq!((2024, 0), (solver.year(), solver.day()));
+    }
+
+    #[test]
+    fn solve_part_should_fail_for_part_3() {
+        assert_eq!(Err(SolutionError::InvalidPart(3)), solve_part(&Sum, &vec![1], 3));
+    }
+
+    #[test]
+    fn run_should_fail_for_part_3() {
+        assert_eq!(Err(SolutionError::InvalidPart(3)), Sum.run("2", &[1, 3]));
+    }
+
+    #[test]
+    fn run_should_fail_for_invalid_input() {
+        assert_eq!(Err(SolutionError::InvalidInput("x".to_string())), Sum.run("2\nx", &[1]));
+    }
+}
@@ -0,0 +1,51 @@
+//! A temporary directory for the tests, that is removed again when the test
+//! is done. It is used by the unit tests and the integration tests.
+
+use std::path::{Path, PathBuf};
+
+/// An empty directory below the temporary directory of the system. The
+/// directory is removed together with its content when the `TempDir` is
+/// dropped.
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    /// Creates a new empty `TempDir`. Leftovers of an earlier run with the
+    /// same `name` are removed.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the directory, that is unique within a test
+    ///   binary. The id of the process is appended to it.
+    pub fn new(name: &str) -> Self {
+        let path = std::env::temp_dir()
+            .join(format!("aoc-core-{}-{}", name, std::process::id()));
+
+        let _ = std::fs::remove_dir_all(&path);
+
+        std::fs::create_dir_all(&path).unwrap();
+
+        Self { path }
+    }
+
+    /// Returns the path of the directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the path of `path` inside of the directory.
+    ///
+    /// # Arguments
+    ///
+    /// - `path` - The path relative to the directory.
+    pub fn join(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.path.join(path)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
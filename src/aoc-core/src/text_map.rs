@@ -1,56 +1,157 @@
-use crate::primitives::Rect;
-
-/// A 2D grid of characters.
-#[derive(Debug, Clone, Eq, PartialEq)]
-pub struct TextMap {
-    data: Vec<char>,
-    width: usize,
-    height: usize,
+use std::fmt::{self, Display};
+
+use thiserror::Error;
+
+use crate::{
+    grid::{Grid, GridError},
+    primitives::{Direction, Position, Rect},
+};
+
+/// A 2D grid of characters that is read from a text, a [`Grid`] of `char`.
+pub type TextMap = Grid<char>;
+
+/// Errors that can happen while creating a [`TextMap`] from a text.
+#[derive(Error, Debug, Clone, Eq, PartialEq)]
+pub enum TextMapError {
+    /// The text does not contain any characters.
+    #[error("The text map is empty.")]
+    Empty,
+
+    /// A row has a different width than the first row.
+    #[error("Row {0} of the text map has a width of {1}, but a width of {2} was expected.")]
+    RaggedRow(usize, usize, usize),
 }
 
-impl TextMap {
-    /// Returns the character at the given `x` and `y` coordinates.
-    /// 
+/// An occurence of a word in a [`TextMap`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct WordMatch {
+    /// The position of the first character of the word.
+    pub start: Position,
+
+    /// The direction the word is read in.
+    pub direction: Direction,
+
+    /// The rectangle from the first character `(x1, y1)` to the last
+    /// character `(x2, y2)` of the word, in the form [`Grid::slice`] takes
+    /// it, i. e. it is not normalized.
+    pub rect: Rect,
+}
+
+/// The eight ways a pattern can be rotated and mirrored. Each symmetry maps a
+/// cell of the transformed pattern back to the cell of the original pattern.
+#[derive(Debug, Clone, Copy)]
+enum Symmetry {
+    Identity,
+    RotateRight,
+    RotateHalf,
+    RotateLeft,
+    FlipHorizontal,
+    FlipVertical,
+    Transpose,
+    AntiTranspose,
+}
+
+impl Symmetry {
+    const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::RotateRight,
+        Symmetry::RotateHalf,
+        Symmetry::RotateLeft,
+        Symmetry::FlipHorizontal,
+        Symmetry::FlipVertical,
+        Symmetry::Transpose,
+        Symmetry::AntiTranspose,
+    ];
+
+    /// Returns the width and height of a `width` x `height` pattern after the
+    /// transformation.
+    fn size(self, width: usize, height: usize) -> (usize, usize) {
+        match self {
+            Symmetry::RotateRight | Symmetry::RotateLeft
+                | Symmetry::Transpose | Symmetry::AntiTranspose => (height, width),
+            _ => (width, height),
+        }
+    }
+
+    /// Returns `true` if the transformations give the same image of `pattern`.
+    fn same_image(self, other: Symmetry, pattern: &TextMap) -> bool {
+        let (width, height) = self.size(pattern.width, pattern.height);
+
+        (width, height) == other.size(pattern.width, pattern.height)
+            && (0..height).all(|y| (0..width).all(|x| {
+                pattern[self.source(x, y, pattern.width, pattern.height)]
+                    == pattern[other.source(x, y, pattern.width, pattern.height)]
+            }))
+    }
+
+    /// Returns the cell of a `width` x `height` pattern that ends up at `(x, y)`
+    /// after the transformation.
+    fn source(self, x: usize, y: usize, width: usize, height: usize) -> Position {
+        match self {
+            Symmetry::Identity => Position::new(x, y),
+            Symmetry::RotateRight => Position::new(y, height - 1 - x),
+            Symmetry::RotateHalf => Position::new(width - 1 - x, height - 1 - y),
+            Symmetry::RotateLeft => Position::new(width - 1 - y, x),
+            Symmetry::FlipHorizontal => Position::new(width - 1 - x, y),
+            Symmetry::FlipVertical => Position::new(x, height - 1 - y),
+            Symmetry::Transpose => Position::new(y, x),
+            Symmetry::AntiTranspose => Position::new(width - 1 - y, height - 1 - x),
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Creates a new `TextMap` from the given string like
+    /// [`TextMap::try_from`], but rows that are shorter than the longest row
+    /// are padded with `fill` at the end.
+    ///
     /// # Arguments
-    /// 
-    /// - `x` - The x-coordinate.
-    /// - `y` - The y-coordinate.
-    pub fn char_at(&self, x: usize, y: usize) -> char {
-        self.data[self.index_of(x, y)]
+    ///
+    /// - `s` - The string to create the `TextMap` from.
+    /// - `fill` - The character to pad short rows with.
+    pub fn from_str_padded(s: &str, fill: char) -> Result<Self, TextMapError> {
+        let lines = non_empty_lines(s)?;
+        let width = lines.iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        Ok(Self::from_lines(&lines, width, fill))
     }
 
-    /// Returns the index of the given `x` and `y` coordinates.
+    fn from_lines(lines: &[&str], width: usize, fill: char) -> Self {
+        let data: Vec<char> = lines.iter()
+            .flat_map(|line| line.chars().chain(std::iter::repeat(fill)).take(width))
+            .collect();
+
+        Self::from_vec(width, lines.len(), data)
+    }
+
+    /// Returns the character at the given `x` and `y` coordinates.
     /// 
     /// # Arguments
     /// 
     /// - `x` - The x-coordinate.
     /// - `y` - The y-coordinate.
-    fn index_of(&self, x: usize, y: usize) -> usize {
-        y * self.width + x
-    }
-
-    /// Returns the width of the grid.
-    pub fn width(&self) -> usize {
-        self.width
-    }
-
-    /// Returns the height of the grid.
-    pub fn height(&self) -> usize {
-        self.height
+    pub fn char_at(&self, x: usize, y: usize) -> char {
+        self[Position { x, y }]
     }
 
-    /// Sets the character at the given `x` and `y` coordinates to `c`. This
-    /// method is not implementing any bounds checking.
+    /// Sets the character at the given `x` and `y` coordinates to `c`.
     /// 
     /// # Arguments
     /// 
     /// - `x` - The x-coordinate.
     /// - `y` - The y-coordinate.
     /// - `c` - The character to set.
+    ///
+    /// # Panics
+    ///
+    /// Panics with [`GridError::OutOfBounds`] if the coordinates are outside
+    /// of the map. Use [`get_mut`](Grid::get_mut) to set a character without
+    /// panicking.
     pub fn set_char(&mut self, x: usize, y: usize, c: char) {
-        let index = self.index_of(x, y);
-
-        self.data[index] = c;
+        self.set(Position { x, y }, c);
     }
 
     /// Returns a slice of the grid from `(x1, y1)` to `(x2, y2)`. The method
@@ -58,7 +159,7 @@ impl TextMap {
     /// a slope of 1 or -1. Other diagonal slices are not possible.
     /// 
     /// The method is checking the bounds of the slice and panics if the slice
-    /// is out of bounds.
+    /// is out of bounds. Use [`Grid::try_slice`] to get an error instead.
     /// 
     /// # Arguments
     /// 
@@ -67,100 +168,49 @@ impl TextMap {
     /// - `x2` - The x-coordinate of the second point.
     /// - `y2` - The y-coordinate of the second point.
     pub fn slice(&self, x1: usize, y1: usize, x2: usize, y2: usize) -> String {
-        let mut result = String::new();
-
-        // bounds check
-        if x1 > self.width - 1
-            || x2 > self.width - 1
-            || y1 > self.height - 1
-            || y2 > self.height - 1
-        {
-            panic!(
-                "Access is out of bounds: (x1={}, y1={})(x2={}, y2={}) (width={}, height={})!",
-                x1, y1, x2, y2, self.width, self.height);
-        }
-
-        if y1 == y2 {
-            if x2 > x1 {
-                // EAST
-
-                for xn in x1..=x2 {
-                    result.push(self.char_at(xn, y1));
-                }
-            } else if x2 < x1 {
-                // WEST
-
-                for xn in (x2..=x1).rev() {
-                    result.push(self.char_at(xn, y1));
-                }
-            } else {
-                // CENTER y1 == y2 && x1 == x2
-
-                result.push(self.char_at(x1, y1));
-            }
-        } else if y2 < y1 {
-            if x1 == x2 {
-                // NORTH
-
-                for yn in (y2..=y1).rev() {
-                    result.push(self.char_at(x2, yn));
-                }
-            } else if x2 > x1 {
-                // NORTH-EAST
-
-                let mut xn = x1;
-
-                for yn in (y2..=y1).rev() {
-                    result.push(self.char_at(xn, yn));
-
-                    xn += 1;
-                }
-            } else {
-                // NORTH-WEST
-
-                let mut xn = x1;
-
-                for yn in (y2..=y1).rev() {
-                    result.push(self.char_at(xn, yn));
+        self.try_slice(x1, y1, x2, y2).unwrap_or_else(|e| panic!("{}", e))
+    }
 
-                    if xn > 0 {
-                        xn -= 1;
-                    }
-                }
+    /// Returns a slice of the grid from `(x1, y1)` to `(x2, y2)` like
+    /// [`Grid::slice`], but returns an error instead of panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `x1` - The x-coordinate of the first point.
+    /// - `y1` - The y-coordinate of the first point.
+    /// - `x2` - The x-coordinate of the second point.
+    /// - `y2` - The y-coordinate of the second point.
+    pub fn try_slice(&self, x1: usize, y1: usize, x2: usize, y2: usize)
+        -> Result<String, GridError>
+    {
+        let start = Position::new(x1, y1);
+        let end = Position::new(x2, y2);
+
+        for pos in [start, end] {
+            if !self.in_bounds(pos) {
+                return Err(GridError::OutOfBounds(pos, self.width, self.height));
             }
-        } else {
-            if x1 == x2 {
-                // SOUTH
-
-                for yn in y1..=y2 {
-                    result.push(self.char_at(x1, yn));
-                }
-            } else if x2 > x1 {
-                // SOUTH-EAST
+        }
 
-                let mut xn = x1;
+        let offset = end - start;
 
-                for yn in y1..=y2 {
-                    result.push(self.char_at(xn, yn));
+        if offset.dx != 0 && offset.dy != 0 && offset.dx.abs() != offset.dy.abs() {
+            return Err(GridError::InvalidSlice(start, end));
+        }
 
-                    xn += 1;
-                }
-            } else {
-                // SOUTH-WEST
+        let mut result = String::from(self[start]);
 
-                let mut xn = x1;
+        if let Some(direction) = Direction::from_offset(offset.signum()) {
+            let mut pos = start;
 
-                for yn in y1..=y2 {
-                    result.push(self.char_at(xn, yn));
+            while pos != end {
+                pos = pos.checked_step(direction, self.bounds()).unwrap();
 
-                    if xn > 0 {
-                        xn -= 1;
-                    }
-                }
+                result.push(self[pos]);
             }
         }
 
-        result
+        Ok(result)
     }
 
     /// Returns a new `TextMap` that represents the rectangle defined by `r`.
@@ -169,31 +219,207 @@ impl TextMap {
     /// method panics if the rectangle is not normalized.
     /// 
     /// The method is checking the bounds of the rectangle and panics if the
-    /// rectangle is out of bounds.
+    /// rectangle is out of bounds. Use [`Grid::try_rect`] to get an error
+    /// instead.
     /// 
     /// # Arguments
     /// 
     /// - `r` - The rectangle to get.
     pub fn rect(&self, r: Rect) -> TextMap {
-        if r.x2 < r.x1 || r.y2 < r.y1 {
-            panic!("Only rectangles allowed, where the rectangle is normalized (x2 < x1 || y2 < y1): {:?}", r);
+        self.try_rect(r).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Returns a new `TextMap` that represents the rectangle defined by `r`
+    /// like [`Grid::rect`], but returns an error instead of panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `r` - The rectangle to get.
+    pub fn try_rect(&self, r: Rect) -> Result<TextMap, GridError> {
+        self.try_crop(r)
+    }
+
+    /// Returns all occurences of `word` that are read in one of the
+    /// `directions`, row by row by their start. The word has to fit into
+    /// the grid, i. e. it is not wrapped around at the edges. Use
+    /// [`Direction::ALL`] to find the word in any direction and
+    /// [`Direction::FORWARD`] to find it only when read forwards.
+    ///
+    /// # Arguments
+    ///
+    /// - `word` - The word to find.
+    /// - `directions` - The directions to read the word in.
+    pub fn find_word(&self, word: &str, directions: &[Direction]) -> Vec<WordMatch> {
+        self.find_word_with(word, directions, |pos, direction| {
+            self.ray(pos, direction).collect()
+        })
+    }
+
+    /// Returns all occurences of `word` that are read in one of the
+    /// `directions`, row by row by their start. Other than
+    /// [`Grid::find_word`] the word continues on the opposite edge, when it
+    /// leaves the grid.
+    ///
+    /// # Arguments
+    ///
+    /// - `word` - The word to find.
+    /// - `directions` - The directions to read the word in.
+    pub fn find_word_wrapping(&self, word: &str, directions: &[Direction]) -> Vec<WordMatch> {
+        let width = self.width as isize;
+        let height = self.height as isize;
+
+        self.find_word_with(word, directions, |pos, direction| {
+            let offset = direction.offset();
+
+            std::iter::successors(Some(pos), |pos| Some(Position::new(
+                (pos.x as isize + offset.dx).rem_euclid(width) as usize,
+                (pos.y as isize + offset.dy).rem_euclid(height) as usize)))
+                .take(word.chars().count())
+                .collect()
+        })
+    }
+
+    fn find_word_with(
+        &self,
+        word: &str,
+        directions: &[Direction],
+        positions: impl Fn(Position, Direction) -> Vec<Position>,
+    ) -> Vec<WordMatch> {
+        let mut occurences: Vec<WordMatch> = vec![];
+        let len = word.chars().count();
+
+        let Some(first) = word.chars().next() else {
+            return occurences;
+        };
+
+        for start in self.positions(|&c| c == first) {
+            for &direction in directions {
+                let mut positions = positions(start, direction);
+                positions.truncate(len);
+
+                if positions.iter().map(|&pos| self[pos]).eq(word.chars()) {
+                    let end = positions[len - 1];
+
+                    occurences.push(WordMatch {
+                        start,
+                        direction,
+                        rect: Rect::new(start.x, start.y, end.x, end.y),
+                    });
+                }
+            }
         }
 
-        if r.x2 > self.width - 1 || r.y2 > self.height - 1 {
-            panic!("Rectangle is out of bounds: r={:?} (width={}, height={})", r, self.width, self.height);
+        occurences
+    }
+
+    /// Returns all rectangles, row by row, where `pattern` matches the grid.
+    /// Cells of the pattern that contain `wildcard` match any character.
+    ///
+    /// # Arguments
+    ///
+    /// - `pattern` - The pattern to find.
+    /// - `wildcard` - The character in the pattern that matches anything.
+    pub fn find_pattern<'a>(&'a self, pattern: &'a TextMap, wildcard: char)
+        -> impl Iterator<Item = Rect> + 'a
+    {
+        self.find_pattern_with(pattern, wildcard, &[Symmetry::Identity])
+    }
+
+    /// Returns all rectangles, row by row, where `pattern` or one of its
+    /// rotations and mirrorings matches the grid. A rectangle is only
+    /// returned once, even if several orientations match it. Cells of the
+    /// pattern that contain `wildcard` match any character.
+    ///
+    /// # Arguments
+    ///
+    /// - `pattern` - The pattern to find.
+    /// - `wildcard` - The character in the pattern that matches anything.
+    pub fn find_pattern_in_any_orientation<'a>(&'a self, pattern: &'a TextMap, wildcard: char)
+        -> impl Iterator<Item = Rect> + 'a
+    {
+        self.find_pattern_with(pattern, wildcard, &Symmetry::ALL)
+    }
+
+    fn find_pattern_with<'a>(
+        &'a self, pattern: &'a TextMap, wildcard: char, symmetries: &[Symmetry]
+    ) -> impl Iterator<Item = Rect> + 'a {
+        // symmetric patterns give the same image for several symmetries
+        let mut images: [Option<Symmetry>; 8] = [None; 8];
+
+        for (i, &symmetry) in symmetries.iter().enumerate() {
+            if !symmetries[..i].iter().any(|&other| other.same_image(symmetry, pattern)) {
+                images[i] = Some(symmetry);
+            }
         }
 
+        // the transformed patterns have at most two different sizes
+        let size = (pattern.width, pattern.height);
+        let swapped = (pattern.height, pattern.width);
+        let has_size = |size: (usize, usize)| {
+            !pattern.data.is_empty()
+                && images.iter().flatten()
+                    .any(|symmetry| symmetry.size(pattern.width, pattern.height) == size)
+        };
+        let sizes = [
+            has_size(size).then_some(size),
+            (swapped != size && has_size(swapped)).then_some(swapped),
+        ];
+
+        self.iter()
+            .map(|(pos, _)| pos)
+            .flat_map(move |top_left| sizes.into_iter().flatten().map(move |size| (top_left, size)))
+            .filter(move |&(top_left, (width, height))| {
+                top_left.x + width <= self.width
+                    && top_left.y + height <= self.height
+                    && images.iter().flatten().any(|&symmetry| {
+                        symmetry.size(pattern.width, pattern.height) == (width, height)
+                            && self.pattern_matches_at(pattern, wildcard, symmetry, top_left)
+                    })
+            })
+            .map(|(top_left, (width, height))| {
+                Rect::new(top_left.x, top_left.y, top_left.x + width - 1, top_left.y + height - 1)
+            })
+    }
+
+    fn pattern_matches_at(
+        &self, pattern: &TextMap, wildcard: char, symmetry: Symmetry, top_left: Position
+    ) -> bool {
+        let (width, height) = symmetry.size(pattern.width, pattern.height);
+
+        (0..height).all(|y| (0..width).all(|x| {
+            let expected = pattern[symmetry.source(x, y, pattern.width, pattern.height)];
+
+            expected == wildcard || expected == self.char_at(top_left.x + x, top_left.y + y)
+        }))
+    }
+
+    /// Renders this grid and `other` side by side, separated by `|`, followed
+    /// by a third column that marks changed cells with `*` and unchanged
+    /// cells with `.`. Cells outside of the smaller grid are rendered as
+    /// spaces.
+    ///
+    /// # Arguments
+    ///
+    /// - `other` - The grid to compare with.
+    pub fn side_by_side(&self, other: &TextMap) -> String {
+        let width = self.width.max(other.width);
+        let cell = |map: &TextMap, x: usize, y: usize| {
+            map.get(Position::new(x, y)).copied().unwrap_or(' ')
+        };
+
         let mut result = String::new();
 
-        for y in r.y1..=r.y2 {
-            for x in r.x1..=r.x2 {
-                result.push(self.char_at(x, y));
-            }
+        for y in 0..self.height.max(other.height) {
+            let old: String = (0..self.width).map(|x| cell(self, x, y)).collect();
+            let new: String = (0..other.width).map(|x| cell(other, x, y)).collect();
+            let changes: String = (0..width)
+                .map(|x| if cell(self, x, y) == cell(other, x, y) { '.' } else { '*' })
+                .collect();
 
-            result.push('\n');
+            result.push_str(&format!("{} | {} | {}\n", old, new, changes));
         }
 
-        TextMap::from(result.as_str())
+        result
     }
 
     /// Returns the position of the given character `c` in the grid. The
@@ -204,14 +430,7 @@ impl TextMap {
     /// 
     /// - `c` - The character to find.
     pub fn find_char_pos(&self, c: char) -> Option<(usize, usize)> {
-        if let Some(index) = self.data.iter().position(|ch| ch == &c) {
-            let y = index / self.width;
-            let x = index % self.width;
-
-            Some((x, y))
-        } else {
-            None
-        }
+        self.position(&c).map(|pos| (pos.x, pos.y))
     }
 
     /// Returns the number of occurences of the given character `c` in the grid.
@@ -220,38 +439,71 @@ impl TextMap {
     /// 
     /// - `c` - The character to count.
     pub fn count_chars(&self, c: char) -> usize {
-        self.data.iter().filter(|&ch| ch == &c).count()
-    }    
+        self.count(&c)
+    }
 }
 
-impl From<&str> for TextMap {
+impl TryFrom<&str> for Grid<char> {
+    type Error = TextMapError;
+
     /// Creates a new `TextMap` from the given string. The string is expected
-    /// to have line breaks at the end of each line.
+    /// to have line breaks (`\n` or `\r\n`) at the end of each line. Empty
+    /// lines at the end are ignored. All rows must have the same width, use
+    /// [`Grid::from_str_padded`] for texts with rows of different widths.
     /// 
     /// # Arguments
     /// 
     /// - `s` - The string to create the `TextMap` from.
-    fn from(s: &str) -> Self {
-        let lines: Vec<&str> = s.lines().collect();
-        let height = lines.len();
-        let width = lines.iter()
-            .map(|line| line.chars().count())
-            .max()
-            .unwrap_or(0);
-        let data: Vec<char> = lines.iter()
-            .flat_map(|line| line.chars()).collect();
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let lines = non_empty_lines(s)?;
+        let width = lines[0].chars().count();
 
-        Self {
-            data,
-            width,
-            height,
+        if let Some((row, line)) = lines.iter()
+            .enumerate()
+            .find(|(_, line)| line.chars().count() != width)
+        {
+            return Err(TextMapError::RaggedRow(row, line.chars().count(), width));
         }
+
+        Ok(Self::from_lines(&lines, width, ' '))
+    }
+}
+
+impl Display for Grid<char> {
+    /// Renders the grid as text with a line break at the end of each row, i.
+    /// e. in the form [`TextMap::try_from`] reads it.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            writeln!(f, "{}", self.row(y).iter().collect::<String>())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the lines of `s` without the empty lines at the end. Fails if
+/// there are no characters at all.
+fn non_empty_lines(s: &str) -> Result<Vec<&str>, TextMapError> {
+    let mut lines: Vec<&str> = s.lines().collect();
+
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+
+    if lines.is_empty() {
+        Err(TextMapError::Empty)
+    } else {
+        Ok(lines)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{primitives::Rect, text_map::TextMap};
+    use crate::{
+        grid::GridError,
+        primitives::{Direction, Position, Rect},
+        text_map::{TextMap, TextMapError, WordMatch},
+    };
 
     const EXAMPLE_MAP: &str = r"MMMSXXMASM
 MSAMXMSMSA
@@ -266,51 +518,78 @@ MXMXAXMASX";
 
     #[test]
     fn text_map_from_str_should_calculate_10_as_width() {
-        let text_map: TextMap = TextMap::from(EXAMPLE_MAP);
+        let text_map: TextMap = TextMap::try_from(EXAMPLE_MAP).unwrap();
 
         assert_eq!(10, text_map.width);
     }
 
     #[test]
     fn text_map_from_str_should_calculate_10_as_height() {
-        let text_map: TextMap = TextMap::from(EXAMPLE_MAP);
+        let text_map: TextMap = TextMap::try_from(EXAMPLE_MAP).unwrap();
 
         assert_eq!(10, text_map.height);
     }
 
     #[test]
     fn text_map_from_str_should_have_data_as_flat_array() {
-        let text_map: TextMap = TextMap::from(EXAMPLE_MAP);
+        let text_map: TextMap = TextMap::try_from(EXAMPLE_MAP).unwrap();
 
         let expected: &str = r"MMMSXXMASMMSAMXMSMSAAMXSXMAAMMMSAMASMSMXXMASAMXAMMXXAMMXXAMASMSMSASXSSSAXAMASAAAMAMMMXMMMMMXMXAXMASX";
 
         assert_eq!(expected.chars().collect::<Vec<char>>(), text_map.data);
     }
 
+    #[test]
+    fn try_from_should_fail_for_empty_text() {
+        assert_eq!(Err(TextMapError::Empty), TextMap::try_from(""));
+        assert_eq!(Err(TextMapError::Empty), TextMap::try_from("\n\n"));
+    }
+
+    #[test]
+    fn try_from_should_fail_for_ragged_rows() {
+        assert_eq!(Err(TextMapError::RaggedRow(1, 2, 3)), TextMap::try_from("abc\nde\nfgh"));
+    }
+
+    #[test]
+    fn try_from_should_handle_crlf_line_breaks() {
+        let text_map = TextMap::try_from("ab\r\ncd\r\n").unwrap();
+
+        assert_eq!((2, 2), (text_map.width, text_map.height));
+        assert_eq!(vec!['a', 'b', 'c', 'd'], text_map.data);
+    }
+
+    #[test]
+    fn from_str_padded_should_pad_short_rows() {
+        let text_map = TextMap::from_str_padded("abc\nd\nef\n", '.').unwrap();
+
+        assert_eq!(3, text_map.width);
+        assert_eq!("abcd..ef.".chars().collect::<Vec<char>>(), text_map.data);
+    }
+
     #[test]
     fn char_at_should_return_m_for_0_0() {
-        let text_map: TextMap = TextMap::from(EXAMPLE_MAP);
+        let text_map: TextMap = TextMap::try_from(EXAMPLE_MAP).unwrap();
 
         assert_eq!('M', text_map.char_at(0, 0));
     }
 
     #[test]
     fn char_at_should_return_m_for_9_0() {
-        let text_map: TextMap = TextMap::from(EXAMPLE_MAP);
+        let text_map: TextMap = TextMap::try_from(EXAMPLE_MAP).unwrap();
 
         assert_eq!('M', text_map.char_at(9, 0));
     }
 
     #[test]
     fn char_at_should_return_m_for_0_9() {
-        let text_map: TextMap = TextMap::from(EXAMPLE_MAP);
+        let text_map: TextMap = TextMap::try_from(EXAMPLE_MAP).unwrap();
 
         assert_eq!('M', text_map.char_at(0, 9));
     }
 
     #[test]
     fn char_at_should_return_x_for_9_9() {
-        let text_map: TextMap = TextMap::from(EXAMPLE_MAP);
+        let text_map: TextMap = TextMap::try_from(EXAMPLE_MAP).unwrap();
 
         assert_eq!('X', text_map.char_at(9, 9));
     }
@@ -322,7 +601,7 @@ const TEST_DATA_CENTRAL: &str = r"..........
     
     #[test]
     fn slice_should_get_central_slice() {
-        let text_map = TextMap::from(TEST_DATA_CENTRAL);
+        let text_map = TextMap::try_from(TEST_DATA_CENTRAL).unwrap();
 
         assert_eq!("X", text_map.slice(4, 1, 4, 1));
     }
@@ -334,7 +613,7 @@ const TEST_DATA_NORTH: &str = r"..S.......
     
     #[test]
     fn slice_should_get_northern_slice() {
-        let text_map = TextMap::from(TEST_DATA_NORTH);
+        let text_map = TextMap::try_from(TEST_DATA_NORTH).unwrap();
 
         assert_eq!("XMAS", text_map.slice(2, 3, 2, 0));
     }
@@ -346,7 +625,7 @@ const TEST_DATA_NORTH: &str = r"..S.......
     
     #[test]
     fn slice_should_get_north_eastern_slice() {
-        let text_map = TextMap::from(TEST_DATA_NORTH_EAST);
+        let text_map = TextMap::try_from(TEST_DATA_NORTH_EAST).unwrap();
 
         assert_eq!("XMAS", text_map.slice(2, 3, 5, 0));
     }
@@ -358,7 +637,7 @@ const TEST_DATA_NORTH: &str = r"..S.......
     
     #[test]
     fn slice_should_get_eastern_slice() {
-        let text_map = TextMap::from(TEST_DATA_EAST);
+        let text_map = TextMap::try_from(TEST_DATA_EAST).unwrap();
 
         assert_eq!("XMAS", text_map.slice(6, 0, 9, 0));
     }
@@ -370,7 +649,7 @@ const TEST_DATA_NORTH: &str = r"..S.......
     
     #[test]
     fn slice_should_get_south_eastern_slice() {
-        let text_map = TextMap::from(TEST_DATA_SOUTH_EAST);
+        let text_map = TextMap::try_from(TEST_DATA_SOUTH_EAST).unwrap();
 
         assert_eq!("XMAS", text_map.slice(0, 0, 3, 3));
     }
@@ -382,7 +661,7 @@ const TEST_DATA_NORTH: &str = r"..S.......
 
     #[test]
     fn slice_should_get_southern_slice() {
-        let text_map = TextMap::from(TEST_DATA_SOUTH);
+        let text_map = TextMap::try_from(TEST_DATA_SOUTH).unwrap();
 
         assert_eq!("XMAS", text_map.slice(9, 0, 9, 3));
     }
@@ -394,7 +673,7 @@ S.........";
 
     #[test]
     fn slice_should_get_south_western_slice() {
-        let text_map = TextMap::from(TEST_DATA_SOUTH_WEST);
+        let text_map = TextMap::try_from(TEST_DATA_SOUTH_WEST).unwrap();
 
         assert_eq!("XMAS", text_map.slice(3, 0, 0, 3));
     }
@@ -406,7 +685,7 @@ SAMX......";
 
     #[test]
     fn slice_should_get_western_slice() {
-        let text_map = TextMap::from(TEST_DATA_WEST);
+        let text_map = TextMap::try_from(TEST_DATA_WEST).unwrap();
 
         assert_eq!("XMAS", text_map.slice(3, 3, 0, 3));
     }
@@ -418,39 +697,65 @@ SAMX......";
     
     #[test]
     fn slice_should_get_north_western_slice() {
-        let text_map = TextMap::from(TEST_DATA_NORTH_WEST);
+        let text_map = TextMap::try_from(TEST_DATA_NORTH_WEST).unwrap();
 
         assert_eq!("XMAS", text_map.slice(3, 3, 0, 0));
     }
 
+    #[test]
+    fn set_char_should_panic_when_out_of_bounds() {
+        let mut text_map = TextMap::try_from("abc\ndef").unwrap();
+
+        assert!(std::panic::catch_unwind(move || text_map.set_char(3, 0, 'x')).is_err());
+    }
+
     #[test]
     fn slice_should_panics_when_x1_out_of_bounds() {
-        let text_map = TextMap::from(TEST_DATA_NORTH_WEST);
+        let text_map = TextMap::try_from(TEST_DATA_NORTH_WEST).unwrap();
 
         assert!(std::panic::catch_unwind(|| text_map.slice(10, 0, 0, 3)).is_err());
     }
 
     #[test]
     fn slice_should_panics_when_y1_out_of_bounds() {
-        let text_map = TextMap::from(TEST_DATA_NORTH_WEST);
+        let text_map = TextMap::try_from(TEST_DATA_NORTH_WEST).unwrap();
 
         assert!(std::panic::catch_unwind(|| text_map.slice(0, 10, 0, 3)).is_err());
     }
 
     #[test]
     fn slice_should_panics_when_x2_out_of_bounds() {
-        let text_map = TextMap::from(TEST_DATA_NORTH_WEST);
+        let text_map = TextMap::try_from(TEST_DATA_NORTH_WEST).unwrap();
 
         assert!(std::panic::catch_unwind(|| text_map.slice(0, 0, 10, 3)).is_err());
     }
 
     #[test]
     fn slice_should_panics_when_y2_out_of_bounds() {
-        let text_map = TextMap::from(TEST_DATA_NORTH_WEST);
+        let text_map = TextMap::try_from(TEST_DATA_NORTH_WEST).unwrap();
 
         assert!(std::panic::catch_unwind(|| text_map.slice(0, 0, 0, 10)).is_err());
     }
 
+    #[test]
+    fn try_slice_should_return_offending_coordinates() {
+        let text_map = TextMap::try_from(TEST_DATA_NORTH_WEST).unwrap();
+
+        assert_eq!(
+            Err(GridError::OutOfBounds(Position::new(0, 4), 10, 4)),
+            text_map.try_slice(0, 0, 0, 4));
+        assert_eq!(
+            Err(GridError::InvalidSlice(Position::new(0, 0), Position::new(2, 1))),
+            text_map.try_slice(0, 0, 2, 1));
+    }
+
+    #[test]
+    fn slice_should_panic_when_not_horizontal_vertical_or_diagonal() {
+        let text_map = TextMap::try_from(TEST_DATA_NORTH_WEST).unwrap();
+
+        assert!(std::panic::catch_unwind(|| text_map.slice(0, 0, 2, 1)).is_err());
+    }
+
     const TEST_DATA_RECT: &str = r"..........
 ..........
 ..........
@@ -458,32 +763,44 @@ SAMX......";
 
     #[test]
     fn rect_should_panic_when_x2_less_than_x1() {
-        let text_map = TextMap::from(TEST_DATA_RECT);
+        let text_map = TextMap::try_from(TEST_DATA_RECT).unwrap();
 
         assert!(std::panic::catch_unwind(|| text_map.rect(Rect::new(1, 1, 0, 0))).is_err());
     }
 
     #[test]
     fn rect_should_panic_when_y2_less_than_y1() {
-        let text_map = TextMap::from(TEST_DATA_RECT);
+        let text_map = TextMap::try_from(TEST_DATA_RECT).unwrap();
 
         assert!(std::panic::catch_unwind(|| text_map.rect(Rect::new(1, 1, 0, 0))).is_err());
     }
 
     #[test]
     fn rect_should_panic_when_x2_out_of_bounds() {
-        let text_map = TextMap::from(TEST_DATA_RECT);
+        let text_map = TextMap::try_from(TEST_DATA_RECT).unwrap();
 
         assert!(std::panic::catch_unwind(|| text_map.rect(Rect::new(0, 0, 10, 0))).is_err());
     }
 
     #[test]
     fn rect_should_panic_when_y2_out_of_bounds() {
-        let text_map = TextMap::from(TEST_DATA_RECT);
+        let text_map = TextMap::try_from(TEST_DATA_RECT).unwrap();
 
         assert!(std::panic::catch_unwind(|| text_map.rect(Rect::new(0, 0, 0, 10))).is_err());
     }
 
+    #[test]
+    fn try_rect_should_return_error_instead_of_panicking() {
+        let text_map = TextMap::try_from(TEST_DATA_RECT).unwrap();
+
+        assert_eq!(
+            Err(GridError::NotNormalized(Rect::new(1, 1, 0, 0))),
+            text_map.try_rect(Rect::new(1, 1, 0, 0)));
+        assert_eq!(
+            Err(GridError::OutOfBounds(Position::new(10, 0), 10, 4)),
+            text_map.try_rect(Rect::new(0, 0, 10, 0)));
+    }
+
     const TEST_DATA_RECT_1: &str = r"..234.....
 ..567.....
 ..890.....
@@ -491,14 +808,127 @@ SAMX......";
 
     #[test]
     fn rect_should_get_rect_1() {
-        let text_map = TextMap::from(TEST_DATA_RECT_1);
+        let text_map = TextMap::try_from(TEST_DATA_RECT_1).unwrap();
 
         assert_eq!(
-            TextMap::from("234\n567\n890"),
+            TextMap::try_from("234\n567\n890").unwrap(),
             text_map.rect(Rect::new(2, 0, 4, 2))
         );
     }
 
+    #[test]
+    fn find_word_should_find_word_in_all_directions() {
+        let text_map = TextMap::try_from(TEST_DATA_NORTH_WEST).unwrap();
+
+        assert_eq!(
+            vec![WordMatch {
+                start: Position::new(3, 3),
+                direction: Direction::NorthWest,
+                rect: Rect::new(3, 3, 0, 0),
+            }],
+            text_map.find_word("XMAS", &Direction::ALL));
+    }
+
+    #[test]
+    fn find_word_should_only_search_given_directions() {
+        let text_map = TextMap::try_from(TEST_DATA_WEST).unwrap();
+
+        assert!(text_map.find_word("XMAS", &Direction::FORWARD).is_empty());
+        assert_eq!(1, text_map.find_word("SAMX", &Direction::FORWARD).len());
+    }
+
+    #[test]
+    fn find_word_should_not_wrap_around() {
+        let text_map = TextMap::try_from("AS..XM").unwrap();
+
+        assert!(text_map.find_word("XMAS", &Direction::ALL).is_empty());
+    }
+
+    #[test]
+    fn find_word_wrapping_should_continue_on_opposite_edge() {
+        let text_map = TextMap::try_from("AS..XM").unwrap();
+
+        assert_eq!(
+            vec![WordMatch {
+                start: Position::new(4, 0),
+                direction: Direction::East,
+                rect: Rect::new(4, 0, 1, 0),
+            }],
+            text_map.find_word_wrapping("XMAS", &[Direction::East]));
+    }
+
+    const TEST_DATA_PATTERN: &str = r"M.S.M
+.A.A.
+M.S.M";
+
+    #[test]
+    fn find_pattern_should_treat_wildcard_as_any_character() {
+        let text_map = TextMap::try_from(TEST_DATA_PATTERN).unwrap();
+        let pattern = TextMap::try_from("M.S\n.A.\nM.S").unwrap();
+
+        assert_eq!(
+            vec![Rect::new(0, 0, 2, 2)],
+            text_map.find_pattern(&pattern, '.').collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn find_pattern_should_return_nothing_when_pattern_is_larger_than_map() {
+        let text_map = TextMap::try_from("AB\nCD").unwrap();
+        let pattern = TextMap::try_from("ABC").unwrap();
+
+        assert_eq!(None, text_map.find_pattern(&pattern, '.').next());
+    }
+
+    #[test]
+    fn find_pattern_in_any_orientation_should_find_rotations_and_mirrorings() {
+        let text_map = TextMap::try_from(TEST_DATA_PATTERN).unwrap();
+        let pattern = TextMap::try_from("M.S\n.A.\nM.S").unwrap();
+
+        assert_eq!(
+            vec![Rect::new(0, 0, 2, 2), Rect::new(2, 0, 4, 2)],
+            text_map.find_pattern_in_any_orientation(&pattern, '.').collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn find_pattern_in_any_orientation_should_swap_width_and_height() {
+        let text_map = TextMap::try_from("A.\nB.\nC.").unwrap();
+        let pattern = TextMap::try_from("CBA").unwrap();
+
+        assert_eq!(
+            vec![Rect::new(0, 0, 0, 2)],
+            text_map.find_pattern_in_any_orientation(&pattern, '.').collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn find_pattern_in_any_orientation_should_return_rect_once_for_several_orientations() {
+        let text_map = TextMap::try_from("AA\nAA").unwrap();
+        let pattern = TextMap::try_from("A.").unwrap();
+
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 1, 0),
+                Rect::new(0, 0, 0, 1),
+                Rect::new(1, 0, 1, 1),
+                Rect::new(0, 1, 1, 1),
+            ],
+            text_map.find_pattern_in_any_orientation(&pattern, '.').collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn display_should_render_map_as_text() {
+        let text_map = TextMap::try_from(TEST_DATA_RECT_1).unwrap();
+
+        assert_eq!(format!("{}\n", TEST_DATA_RECT_1), text_map.to_string());
+    }
+
+    #[test]
+    fn side_by_side_should_mark_changed_cells() {
+        let old = TextMap::try_from("^.\n..").unwrap();
+        let new = TextMap::try_from("X^\n..").unwrap();
+
+        assert_eq!("^. | X^ | **\n.. | .. | ..\n", old.side_by_side(&new));
+    }
+
     const TEST_DATA_FIND_POS: &str = r"..........
 ....X.....
 ..........
@@ -506,14 +936,14 @@ SAMX......";
 
     #[test]
     fn find_char_pos_should_return_none_for_not_found() {
-        let text_map = TextMap::from(TEST_DATA_FIND_POS);
+        let text_map = TextMap::try_from(TEST_DATA_FIND_POS).unwrap();
 
         assert_eq!(None, text_map.find_char_pos('A'));
     }
 
     #[test]
     fn find_char_pos_should_return_4_1_for_x() {
-        let text_map = TextMap::from(TEST_DATA_FIND_POS);
+        let text_map = TextMap::try_from(TEST_DATA_FIND_POS).unwrap();
 
         assert_eq!(Some((4, 1)), text_map.find_char_pos('X'));
     }
@@ -525,35 +955,35 @@ XMASXMASX";
 
     #[test]
     fn count_chars_should_return_12_for_x() {
-        let text_map = TextMap::from(TEST_DATA_COUNT_OCCURENCES);
+        let text_map = TextMap::try_from(TEST_DATA_COUNT_OCCURENCES).unwrap();
 
         assert_eq!(12, text_map.count_chars('X'));
     }
 
     #[test]
     fn count_chars_should_return_8_for_m() {
-        let text_map = TextMap::from(TEST_DATA_COUNT_OCCURENCES);
+        let text_map = TextMap::try_from(TEST_DATA_COUNT_OCCURENCES).unwrap();
 
         assert_eq!(8, text_map.count_chars('M'));
     }
 
     #[test]
     fn count_chars_should_return_8_for_a() {
-        let text_map = TextMap::from(TEST_DATA_COUNT_OCCURENCES);
+        let text_map = TextMap::try_from(TEST_DATA_COUNT_OCCURENCES).unwrap();
 
         assert_eq!(8, text_map.count_chars('A'));
     }
 
     #[test]
     fn count_chars_should_return_8_for_s() {
-        let text_map = TextMap::from(TEST_DATA_COUNT_OCCURENCES);
+        let text_map = TextMap::try_from(TEST_DATA_COUNT_OCCURENCES).unwrap();
 
         assert_eq!(8, text_map.count_chars('S'));
     }
 
     #[test]
     fn count_chars_should_return_0_for_not_found() {
-        let text_map = TextMap::from(TEST_DATA_COUNT_OCCURENCES);
+        let text_map = TextMap::try_from(TEST_DATA_COUNT_OCCURENCES).unwrap();
 
         assert_eq!(0, text_map.count_chars('Z'));
     }
@@ -0,0 +1,381 @@
+use std::{borrow::Borrow, collections::HashSet, ops::Add};
+
+use crate::{bfs::bfs_levels, graph::Graph};
+
+/// The maximum number of nodes [`held_karp`] and the Hamiltonian path solvers
+/// handle. The memory needed grows with `2^n * n`, so they are meant for
+/// small graphs only.
+pub const HELD_KARP_MAX_NODES: usize = 16;
+
+/// Builds a tour with the nearest neighbour heuristic: starting at `start`,
+/// it always walks to the cheapest neighbor that is not visited yet, until
+/// there is none. The tour does not return to `start`. It is fast, but
+/// neither guaranteed to be the shortest tour nor to visit all nodes.
+///
+/// # Arguments
+///
+/// - `graph` - the graph
+/// - `start` - the starting node
+pub fn nearest_neighbour_tour<G, Q>(graph: &G, start: &Q) -> (Vec<G::Node>, G::Weight)
+where
+    G: Graph,
+    G::Node: Borrow<Q>,
+    Q: ToOwned<Owned = G::Node> + ?Sized,
+{
+    let start = start.to_owned();
+    let mut visited: HashSet<G::Node> = HashSet::from([start.clone()]);
+    let mut tour = vec![start];
+    let mut total_weight = G::Weight::default();
+
+    loop {
+        let next = graph.neighbors(&tour[tour.len() - 1]).into_iter()
+            .filter(|(node, _)| !visited.contains::<G::Node>(node))
+            .min_by_key(|&(_, weight)| weight);
+
+        let Some((node, weight)) = next else {
+            break;
+        };
+
+        total_weight = total_weight + weight;
+        visited.insert(node.clone());
+        tour.push(node);
+    }
+
+    (tour, total_weight)
+}
+
+/// Finds the shortest round trip that starts at `start`, visits every node
+/// that is reachable from `start` exactly once and returns to `start`, by
+/// using the Held-Karp algorithm. The returned tour contains `start` at both
+/// ends. Returns `None` if there is no such round trip or if more than
+/// [`HELD_KARP_MAX_NODES`] nodes are reachable.
+///
+/// # Arguments
+///
+/// - `graph` - the graph
+/// - `start` - the starting node
+pub fn held_karp<G, Q>(graph: &G, start: &Q) -> Option<(Vec<G::Node>, G::Weight)>
+where
+    G: Graph,
+    G::Node: Borrow<Q>,
+    Q: ToOwned<Owned = G::Node> + ?Sized,
+{
+    // the start node gets index 0
+    let nodes: Vec<G::Node> = bfs_levels(graph, start).concat();
+    let n = nodes.len();
+
+    if n > HELD_KARP_MAX_NODES {
+        return None;
+    }
+
+    if n == 1 {
+        return Some((nodes, G::Weight::default()));
+    }
+
+    let weights = weight_matrix(graph, &nodes);
+    let full = (1 << n) - 1;
+    let (costs, previous) = visit_all(&weights, &[0], |a, b| a < b);
+
+    // close the round trip back to the start
+    let (last, cost) = (1..n)
+        .filter_map(|last| Some((last, costs[full * n + last]? + weights[last][0]?)))
+        .min_by_key(|&(_, cost)| cost)?;
+
+    let mut tour = trace_back(&nodes, &previous, last);
+    tour.push(nodes[0].clone());
+
+    Some((tour, cost))
+}
+
+/// Finds the cheapest path that visits every node of `nodes` exactly once.
+/// The path can start and end at any of the nodes. Returns `None` if there is
+/// no such path or if there are more than [`HELD_KARP_MAX_NODES`] nodes.
+///
+/// # Arguments
+///
+/// - `graph` - the graph
+/// - `nodes` - the nodes to visit
+pub fn shortest_hamiltonian_path<G: Graph>(graph: &G, nodes: &[G::Node])
+    -> Option<(Vec<G::Node>, G::Weight)>
+{
+    hamiltonian_path(graph, nodes, |a, b| a < b)
+}
+
+/// Finds the most expensive path that visits every node of `nodes` exactly
+/// once. The path can start and end at any of the nodes. Returns `None` if
+/// there is no such path or if there are more than [`HELD_KARP_MAX_NODES`]
+/// nodes.
+///
+/// # Arguments
+///
+/// - `graph` - the graph
+/// - `nodes` - the nodes to visit
+pub fn longest_hamiltonian_path<G: Graph>(graph: &G, nodes: &[G::Node])
+    -> Option<(Vec<G::Node>, G::Weight)>
+{
+    hamiltonian_path(graph, nodes, |a, b| a > b)
+}
+
+fn hamiltonian_path<G: Graph>(
+    graph: &G,
+    nodes: &[G::Node],
+    better: impl Fn(G::Weight, G::Weight) -> bool,
+) -> Option<(Vec<G::Node>, G::Weight)> {
+    let n = nodes.len();
+
+    if n == 0 || n > HELD_KARP_MAX_NODES {
+        return None;
+    }
+
+    let weights = weight_matrix(graph, nodes);
+    let full = (1 << n) - 1;
+    let starts: Vec<usize> = (0..n).collect();
+    let (costs, previous) = visit_all(&weights, &starts, &better);
+
+    let (last, cost) = (0..n)
+        .filter_map(|last| Some((last, costs[full * n + last]?)))
+        .reduce(|best, next| if better(next.1, best.1) { next } else { best })?;
+
+    Some((trace_back(nodes, &previous, last), cost))
+}
+
+// marks the first node of a path in the table of previous nodes
+const NO_PREVIOUS: u8 = u8::MAX;
+
+// the cost of the best path for every set of visited nodes and last node,
+// together with the node visited before the last one, both indexed by
+// `mask * n + last`
+type VisitTable<W> = (Vec<Option<W>>, Vec<u8>);
+
+// fills the table of the Held-Karp algorithm: costs[mask * n + last] is the
+// cost of the best path that starts at one of `starts`, visits the nodes in
+// mask and ends at last
+fn visit_all<W>(
+    weights: &[Vec<Option<W>>],
+    starts: &[usize],
+    better: impl Fn(W, W) -> bool,
+) -> VisitTable<W>
+where
+    W: Copy + Add<Output = W> + Default,
+{
+    let n = weights.len();
+    let mut costs: Vec<Option<W>> = vec![None; (1 << n) * n];
+    let mut previous: Vec<u8> = vec![NO_PREVIOUS; (1 << n) * n];
+
+    for &start in starts {
+        costs[(1 << start) * n + start] = Some(W::default());
+    }
+
+    for mask in 1..1usize << n {
+        for last in (0..n).filter(|last| mask & (1 << last) != 0) {
+            let Some(cost) = costs[mask * n + last] else {
+                continue;
+            };
+
+            for next in (0..n).filter(|next| mask & (1 << next) == 0) {
+                let Some(weight) = weights[last][next] else {
+                    continue;
+                };
+
+                let index = (mask | (1 << next)) * n + next;
+                let next_cost = cost + weight;
+
+                if costs[index].is_none_or(|known| better(next_cost, known)) {
+                    costs[index] = Some(next_cost);
+                    previous[index] = last as u8;
+                }
+            }
+        }
+    }
+
+    (costs, previous)
+}
+
+// follows the previous nodes back from last, starting with all nodes visited
+fn trace_back<N: Clone>(nodes: &[N], previous: &[u8], last: usize) -> Vec<N> {
+    let n = nodes.len();
+    let mut path = vec![];
+    let mut mask = (1usize << n) - 1;
+    let mut last = last as u8;
+
+    while last != NO_PREVIOUS {
+        let index = last as usize;
+        path.push(nodes[index].clone());
+
+        last = previous[mask * n + index];
+        mask &= !(1 << index);
+    }
+
+    path.reverse();
+
+    path
+}
+
+// returns the weights of the cheapest edges between all pairs of nodes
+fn weight_matrix<G: Graph>(graph: &G, nodes: &[G::Node]) -> Vec<Vec<Option<G::Weight>>> {
+    nodes.iter()
+        .map(|from| {
+            let edges = graph.neighbors(from);
+
+            nodes.iter()
+                .map(|to| {
+                    edges.iter()
+                        .filter(|(node, _)| node == to)
+                        .map(|&(_, weight)| weight)
+                        .min()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        directed_graph::DirectedGraph,
+        simple_paths::SimplePaths,
+        undirected_graph::UndirectedGraph,
+    };
+
+    // the example of day 9 of 2015
+    fn cities() -> UndirectedGraph {
+        let mut graph = UndirectedGraph::new();
+
+        graph.add_edge("London", "Dublin", 464);
+        graph.add_edge("London", "Belfast", 518);
+        graph.add_edge("Dublin", "Belfast", 141);
+
+        graph
+    }
+
+    fn square() -> UndirectedGraph {
+        let mut graph = UndirectedGraph::new();
+
+        graph.add_edge("A", "B", 1);
+        graph.add_edge("B", "C", 1);
+        graph.add_edge("C", "D", 1);
+        graph.add_edge("D", "A", 1);
+        graph.add_edge("A", "C", 10);
+        graph.add_edge("B", "D", 10);
+
+        graph
+    }
+
+    #[test]
+    fn nearest_neighbour_tour_should_walk_to_cheapest_unvisited_neighbor() {
+        assert_eq!(
+            nearest_neighbour_tour(&cities(), "London"),
+            (vec!["London".to_string(), "Dublin".to_string(), "Belfast".to_string()], 605));
+    }
+
+    #[test]
+    fn held_karp_should_find_shortest_round_trip() {
+        let (tour, cost) = held_karp(&square(), "A").unwrap();
+
+        assert_eq!(4, cost);
+        assert_eq!(5, tour.len());
+        assert_eq!(Some(&"A".to_string()), tour.first());
+        assert_eq!(Some(&"A".to_string()), tour.last());
+    }
+
+    #[test]
+    fn held_karp_should_beat_nearest_neighbour_tour() {
+        let mut graph = UndirectedGraph::new();
+
+        // the cheap steps lead to an expensive way back
+        graph.add_edge("A", "B", 1);
+        graph.add_edge("B", "C", 1);
+        graph.add_edge("C", "D", 1);
+        graph.add_edge("D", "A", 100);
+        graph.add_edge("A", "C", 50);
+        graph.add_edge("B", "D", 50);
+
+        let (greedy, greedy_cost) = nearest_neighbour_tour(&graph, "A");
+        let (_, exact_cost) = held_karp(&graph, "A").unwrap();
+
+        assert_eq!(vec!["A", "B", "C", "D"], greedy);
+        assert_eq!(103, greedy_cost + graph.edge_weight("D", "A").unwrap());
+        assert_eq!(102, exact_cost);
+    }
+
+    #[test]
+    fn held_karp_should_return_none_without_round_trip() {
+        let mut graph = DirectedGraph::new();
+
+        graph.add_edge("A", "B", 1);
+        graph.add_edge("B", "C", 1);
+
+        assert_eq!(held_karp(&graph, "A"), None);
+    }
+
+    #[test]
+    fn held_karp_should_return_start_for_single_node() {
+        let mut graph = UndirectedGraph::new();
+
+        graph.add_node("A");
+
+        assert_eq!(held_karp(&graph, "A"), Some((vec!["A".to_string()], 0)));
+    }
+
+    #[test]
+    fn hamiltonian_paths_should_find_shortest_and_longest_route() {
+        let graph = cities();
+        let nodes = graph.get_nodes();
+
+        let (shortest, shortest_cost) = shortest_hamiltonian_path(&graph, &nodes).unwrap();
+        let (longest, longest_cost) = longest_hamiltonian_path(&graph, &nodes).unwrap();
+
+        assert_eq!(605, shortest_cost);
+        assert_eq!(982, longest_cost);
+        assert_eq!(3, shortest.len());
+        assert_eq!(3, longest.len());
+    }
+
+    #[test]
+    fn hamiltonian_paths_should_agree_with_simple_paths() {
+        let graph = square();
+        let nodes = graph.get_nodes();
+
+        let all: Vec<i32> = nodes.iter()
+            .flat_map(|start| SimplePaths::new(&graph, start))
+            .filter(|(path, _)| path.len() == nodes.len())
+            .map(|(_, cost)| cost)
+            .collect();
+
+        assert_eq!(
+            all.iter().min().copied(),
+            shortest_hamiltonian_path(&graph, &nodes).map(|(_, cost)| cost));
+        assert_eq!(
+            all.iter().max().copied(),
+            longest_hamiltonian_path(&graph, &nodes).map(|(_, cost)| cost));
+    }
+
+    #[test]
+    fn held_karp_should_return_none_for_too_many_nodes() {
+        let mut graph = UndirectedGraph::new();
+
+        for i in 1..HELD_KARP_MAX_NODES {
+            graph.add_edge(&i.to_string(), &(i + 1).to_string(), 1);
+        }
+
+        assert_eq!(
+            Some(HELD_KARP_MAX_NODES as i32 - 1),
+            shortest_hamiltonian_path(&graph, &graph.get_nodes()).map(|(_, cost)| cost));
+
+        graph.add_edge(&HELD_KARP_MAX_NODES.to_string(), "too many", 1);
+
+        assert_eq!(held_karp(&graph, "1"), None);
+        assert_eq!(shortest_hamiltonian_path(&graph, &graph.get_nodes()), None);
+    }
+
+    #[test]
+    fn hamiltonian_path_should_return_none_if_nodes_are_not_connected() {
+        let mut graph = UndirectedGraph::new();
+
+        graph.add_edge("A", "B", 1);
+        graph.add_node("C");
+
+        assert_eq!(shortest_hamiltonian_path(&graph, &graph.get_nodes()), None);
+    }
+}
@@ -1,21 +1,41 @@
-use std::collections::HashMap;
-
-/// An undirected graph implementation.
+use crate::{
+    indexed_graph::{EdgePolicy, IndexedGraph},
+    simple_paths::SimplePaths,
+    tsp,
+};
+
+/// An undirected graph implementation. It is a wrapper around an
+/// [`IndexedGraph`] with `String` nodes and `i32` weights.
 #[derive(Debug)]
 pub struct UndirectedGraph {
-    nodes: Vec<String>,
-    edges: Vec<(String, String, i32)>,
+    graph: IndexedGraph<String, i32>,
+}
+
+impl Default for UndirectedGraph {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl UndirectedGraph {
     /// Creates a new graph.
     pub fn new() -> Self {
         Self {
-            nodes: vec![],
-            edges: vec![],
+            graph: IndexedGraph::undirected(),
         }
     }
 
+    /// Sets the policy for adding an edge between two nodes that are already
+    /// connected. The default is [`EdgePolicy::Replace`].
+    ///
+    /// # Arguments
+    ///
+    /// - `policy` - The policy for adding edges.
+    pub fn with_policy(mut self, policy: EdgePolicy) -> Self {
+        self.graph = self.graph.with_policy(policy);
+        self
+    }
+
     /// Adds a node to the graph.
     /// Will not add the node if it already exists and will not throw an error.
     /// 
@@ -23,17 +43,24 @@ impl UndirectedGraph {
     /// 
     /// - `node` - The node to add to the graph.
     pub fn add_node(&mut self, node: &str) {
-        if !self.nodes.contains(&node.to_string()) {
-            self.nodes.push(node.to_string());
+        if self.graph.index_of(node).is_none() {
+            self.graph.add_node(node.to_string());
         }
     }
 
     /// Gets the nodes of the graph.
     pub fn get_nodes(&self) -> Vec<String> {
-        self.nodes.clone()
+        self.graph.nodes().to_vec()
     }
 
-    /// Adds an edge to the graph.
+    /// Returns the underlying [`IndexedGraph`].
+    pub fn as_indexed(&self) -> &IndexedGraph<String, i32> {
+        &self.graph
+    }
+
+    /// Adds an edge to the graph. The edge connects both nodes in both
+    /// directions. If the nodes are already connected, the [`EdgePolicy`] of
+    /// the graph decides about the weight.
     /// 
     /// # Arguments
     /// 
@@ -41,119 +68,89 @@ impl UndirectedGraph {
     /// - `to` - The node the edge is to.
     /// - `weight` - The weight of the edge.
     pub fn add_edge(&mut self, from: &str, to: &str, weight: i32) {
-        // FIXME: This is not save, as it will add the same edge twice in case
-        // there are different weights.
-
-        if !self.edges.contains(&(from.to_string(), to.to_string(), weight)) {
-            self.edges.push((from.to_string(), to.to_string(), weight));
-            self.edges.push((to.to_string(), from.to_string(), weight));
-        }
+        self.graph.add_edge(from.to_string(), to.to_string(), weight);
     }
 
-    /// Gets the edges of a node.
+    /// Removes the edge between `a` and `b`. Returns `true` if there was an
+    /// edge.
     /// 
     /// # Arguments
     /// 
-    /// - `node` - The node to get the edges of.
-    pub fn get_edges(&self, node: &str) -> Vec<(String, i32)> {
-        self.edges.iter()
-            .filter(|(from, _, _)| from == node)
-            .map(|(_, to, weight)| (to.clone(), *weight))
-            .collect()
+    /// - `a` - One node of the edge.
+    /// - `b` - The other node of the edge.
+    pub fn remove_edge(&mut self, a: &str, b: &str) -> bool {
+        self.graph.remove_edge(a, b)
     }
 
-    /// Finds the shortest path from a node to all other possible nodes by using
-    /// a bfs algorithm.
+    /// Removes a node and all of its edges. Returns `true` if the node
+    /// existed.
     /// 
     /// # Arguments
     /// 
-    /// - `from` - The node to start from.
-    pub fn find_shortest_path(&self, from: &str) -> (Vec<String>, i32) {
-        let mut shortest_path = vec![];
-        let mut current = from.to_string();
-        let mut total_weight = 0;
-
-        shortest_path.push(from.to_string());
-
-        loop {
-            let edges = self.get_edges(&current);
-
-            let mut next = None;
-            let mut next_weight = i32::MAX;
-
-            for (node, weight) in edges {
-                if !shortest_path.contains(&node) && weight < next_weight {
-                    next = Some(node);
-                    next_weight = weight;
-                }
-            }
-
-            if let Some(node) = next {
-                total_weight += next_weight;
-
-                shortest_path.push(node.clone());
-                current = node;
-            } else {
-                break;
-            }
-        }
-
-        (shortest_path, total_weight)
+    /// - `node` - The node to remove.
+    pub fn remove_node(&mut self, node: &str) -> bool {
+        self.graph.remove_node(node)
     }
 
-    /// Finds the longest path from a node to all other possible nodes by using
-    /// a dfs algorithm.
+    /// Returns the weight of the edge between `a` and `b` or `None`, if they
+    /// are not connected.
     /// 
     /// # Arguments
     /// 
-    /// - `start` - The node to start from.
-    pub fn longest_path(&self, start: &str) -> (Vec<String>, i32) {
-        let mut visited = HashMap::new();
-        let mut path = Vec::new();
-        let mut max_path = Vec::new();
-        let mut max_weight = 0;
-
-        self.dfs(
-            start, &mut visited, &mut path, 0,
-            &mut max_path, &mut max_weight);
+    /// - `a` - One node of the edge.
+    /// - `b` - The other node of the edge.
+    pub fn edge_weight(&self, a: &str, b: &str) -> Option<i32> {
+        self.graph.edge_weight(a, b)
+    }
 
-        (max_path, max_weight)
+    /// Returns `true` if `a` and `b` are connected by an edge.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `a` - One node of the edge.
+    /// - `b` - The other node of the edge.
+    pub fn has_edge(&self, a: &str, b: &str) -> bool {
+        self.graph.has_edge(a, b)
     }
 
-    /// Depth-first search algorithm.
+    /// Gets the edges of a node.
     /// 
     /// # Arguments
     /// 
-    /// - `node` - The node to start from.
-    /// - `visited` - The nodes that have been visited.
-    /// - `path` - The current path.
-    /// - `current_weight` - The current weight.
-    /// - `max_path` - The longest path.
-    /// - `max_weight` - The longest path weight.
-    pub fn dfs(
-        &self, node: &str, visited: &mut HashMap<String, bool>, 
-        path: &mut Vec<String>, current_weight: i32, max_path: &mut Vec<String>,
-        max_weight: &mut i32,
-    ) {
-        visited.insert(node.to_string(), true);
-        path.push(node.to_string());
-
-        if current_weight > *max_weight {
-            *max_weight = current_weight;
-            *max_path = path.clone();
-        }
+    /// - `node` - The node to get the edges of.
+    pub fn get_edges(&self, node: &str) -> Vec<(String, i32)> {
+        self.graph.index_of(node)
+            .map(|index| {
+                self.graph.edges(index).iter()
+                    .map(|&(to, weight)| (self.graph.node(to).clone(), weight))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
 
-        for (neighbor, weight) in self.get_edges(node).iter() {
-            if !visited.get(neighbor).unwrap_or(&false) {
-                self.dfs(
-                    neighbor, visited, path, 
-                    current_weight + weight,
-                    max_path, max_weight);
-            }
-        }
+    /// Builds a tour with the nearest neighbour heuristic, i. e. it always
+    /// walks to the cheapest neighbor that is not visited yet. See
+    /// [`tsp::nearest_neighbour_tour`] for details and [`tsp::held_karp`] for
+    /// the exact shortest round trip.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `from` - The node to start from.
+    pub fn nearest_neighbour_tour(&self, from: &str) -> (Vec<String>, i32) {
+        tsp::nearest_neighbour_tour(self, from)
+    }
 
-        path.pop();
-        visited.insert(node.to_string(), false);
+    /// Finds the longest simple path, i. e. a path that visits no node
+    /// twice, that starts at `start`. See [`SimplePaths`] to iterate over all
+    /// simple paths.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `start` - The node to start from.
+    pub fn longest_path(&self, start: &str) -> (Vec<String>, i32) {
+        SimplePaths::new(self, start)
+            .reduce(|longest, path| if path.1 > longest.1 { path } else { longest })
+            .unwrap_or_default()
     }
 }
 
@@ -168,9 +165,7 @@ mod tests {
         graph.add_node("London");
         graph.add_node("Dublin");
 
-        assert_eq!(
-            graph.nodes,
-            vec!["London".to_string(), "Dublin".to_string()]);
+        assert_eq!(graph.as_indexed().node_count(), 2);
     }
 
     #[test]
@@ -194,11 +189,43 @@ mod tests {
 
         graph.add_edge("London", "Dublin", 464);
 
-        assert_eq!(
-            graph.edges,
-            vec![
-                ("London".to_string(), "Dublin".to_string(), 464),
-                ("Dublin".to_string(), "London".to_string(), 464)]);
+        assert_eq!(graph.get_edges("London"), vec![("Dublin".to_string(), 464)]);
+        assert_eq!(graph.get_edges("Dublin"), vec![("London".to_string(), 464)]);
+    }
+
+    #[test]
+    fn add_edge_should_not_duplicate_reversed_edge() {
+        let mut graph = UndirectedGraph::new();
+
+        graph.add_edge("London", "Dublin", 464);
+        graph.add_edge("Dublin", "London", 464);
+
+        assert_eq!(graph.get_edges("London"), vec![("Dublin".to_string(), 464)]);
+        assert_eq!(graph.get_edges("Dublin"), vec![("London".to_string(), 464)]);
+    }
+
+    #[test]
+    fn add_edge_should_keep_min_weight_with_keep_min_policy() {
+        let mut graph = UndirectedGraph::new().with_policy(EdgePolicy::KeepMin);
+
+        graph.add_edge("London", "Dublin", 464);
+        graph.add_edge("Dublin", "London", 500);
+
+        assert_eq!(graph.edge_weight("London", "Dublin"), Some(464));
+        assert_eq!(graph.edge_weight("Dublin", "London"), Some(464));
+    }
+
+    #[test]
+    fn remove_node_should_remove_its_edges() {
+        let mut graph = UndirectedGraph::new();
+
+        graph.add_edge("London", "Dublin", 464);
+        graph.add_edge("Dublin", "Paris", 500);
+
+        assert!(graph.remove_node("Dublin"));
+        assert!(!graph.has_edge("London", "Dublin"));
+        assert_eq!(graph.get_nodes(), vec!["London".to_string(), "Paris".to_string()]);
+        assert_eq!(graph.get_edges("Paris"), vec![]);
     }
 
     #[test]
@@ -215,7 +242,7 @@ mod tests {
     }
 
     #[test]
-    fn find_shortest_path_should_return_shortest_path() {
+    fn nearest_neighbour_tour_should_return_greedy_tour() {
         let mut graph = UndirectedGraph::new();
 
         graph.add_node("London");
@@ -228,7 +255,7 @@ mod tests {
         graph.add_edge("Paris", "Berlin", 600);
 
         assert_eq!(
-            graph.find_shortest_path("London"),
+            graph.nearest_neighbour_tour("London"),
             (vec![
                 "London".to_string(), "Dublin".to_string(), "Paris".to_string(),
                 "Berlin".to_string()],
@@ -258,23 +285,15 @@ mod tests {
     }
 
     #[test]
-    fn dfs_should_return_longest_path() {
+    fn longest_path_should_take_heavier_branch() {
         let mut graph = UndirectedGraph::new();
 
-        graph.add_node("London");
-        graph.add_node("Dublin");
-        graph.add_node("Paris");
-        graph.add_node("Berlin");
-
         graph.add_edge("London", "Dublin", 464);
-        graph.add_edge("Dublin", "Paris", 500);
-        graph.add_edge("Paris", "Berlin", 600);
+        graph.add_edge("London", "Paris", 344);
+        graph.add_edge("Paris", "Berlin", 878);
 
         assert_eq!(
             graph.longest_path("London"),
-            (vec![
-                "London".to_string(), "Dublin".to_string(), "Paris".to_string(),
-                "Berlin".to_string()],
-            1564));
+            (vec!["London".to_string(), "Paris".to_string(), "Berlin".to_string()], 1222));
     }
 }
@@ -0,0 +1,98 @@
+mod common;
+
+use std::time::Duration;
+
+use aoc_core::{
+    answer::{AnswerError, AnswerSubmitter, SubmitOutcome},
+    input_source::{AocClient, DiskCache},
+};
+use common::{MockServer, Response, TempDir};
+
+const TOO_HIGH: &str = r#"<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. <a href="/2024/day/1">[Return to Day 1]</a></p></article></main>"#;
+const CORRECT: &str = r#"<main><article><p>That's the right answer!  You are <span class="day-success">one gold star</span> closer to finding the Chief Historian. <a href="/2024/day/1#part2">[Continue to Part Two]</a></p></article></main>"#;
+const RATE_LIMITED: &str = r#"<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 38s left to wait. <a href="/2024/day/1">[Return to Day 1]</a></p></article></main>"#;
+
+fn submitter(server: &MockServer, root: &TempDir) -> AnswerSubmitter {
+    AnswerSubmitter::new(
+        DiskCache::new(root.path()),
+        AocClient::new().with_base_url(&server.url()).with_session_cookie("abc123"))
+}
+
+#[test]
+fn submit_should_post_answer_form() {
+    let server = MockServer::start(|_| Response::new(200, CORRECT));
+    let root = TempDir::new("answer-post");
+
+    submitter(&server, &root).submit(2024, 1, 1, 11).unwrap();
+
+    let requests = server.requests();
+
+    assert_eq!("POST", requests[0].method);
+    assert_eq!("/2024/day/1/answer", requests[0].path);
+    assert_eq!("level=1&answer=11", requests[0].body);
+    assert_eq!(Some("session=abc123"), requests[0].header("Cookie"));
+}
+
+#[test]
+fn submit_should_record_attempt_in_ledger_next_to_input() {
+    let server = MockServer::start(|_| Response::new(200, TOO_HIGH));
+    let root = TempDir::new("answer-ledger");
+
+    assert_eq!(
+        SubmitOutcome::TooHigh,
+        submitter(&server, &root).submit(2024, 1, 1, 100).unwrap());
+
+    let ledger = std::fs::read_to_string(
+        root.join("2024").join("1").join("ledger.txt")).unwrap();
+
+    assert!(ledger.ends_with("\t1\ttoo_high\t100\n"));
+}
+
+#[test]
+fn submit_should_not_post_answers_that_are_known_to_be_wrong() {
+    let server = MockServer::start(|_| Response::new(200, TOO_HIGH));
+    let root = TempDir::new("answer-known");
+    let submitter = submitter(&server, &root);
+
+    submitter.submit(2024, 1, 1, 100).unwrap();
+
+    assert!(matches!(
+        submitter.submit(2024, 1, 1, 100),
+        Err(AnswerError::KnownWrong(1, _))));
+    assert!(matches!(
+        submitter.submit(2024, 1, 1, 150),
+        Err(AnswerError::KnownTooHigh(1, _, _))));
+    assert_eq!(1, server.requests().len());
+}
+
+#[test]
+fn submit_should_answer_known_correct_answer_from_ledger() {
+    let server = MockServer::start(|_| Response::new(200, CORRECT));
+    let root = TempDir::new("answer-correct");
+    let submitter = submitter(&server, &root);
+
+    submitter.submit(2024, 1, 1, 11).unwrap();
+
+    assert_eq!(SubmitOutcome::Correct, submitter.submit(2024, 1, 1, 11).unwrap());
+    assert_eq!(1, server.requests().len());
+}
+
+#[test]
+fn submit_should_return_wait_time_when_rate_limited() {
+    let server = MockServer::start(|_| Response::new(200, RATE_LIMITED));
+    let root = TempDir::new("answer-rate-limited");
+
+    assert_eq!(
+        SubmitOutcome::RateLimited(Duration::from_secs(38)),
+        submitter(&server, &root).submit(2024, 1, 2, 31).unwrap());
+}
+
+#[test]
+fn submit_should_fail_on_unexpected_response() {
+    let server = MockServer::start(|_| Response::new(200, "<main>?</main>"));
+    let root = TempDir::new("answer-unexpected");
+
+    assert!(matches!(
+        submitter(&server, &root).submit(2024, 1, 1, 11),
+        Err(AnswerError::UnexpectedResponse(2024, 1))));
+}
@@ -0,0 +1,100 @@
+mod common;
+
+use std::{sync::Arc, time::Duration};
+
+use aoc_core::{
+    input_source::{AocClient, InputSource},
+    observer::{InputEvent, RecordingObserver},
+    InputError,
+};
+use common::{MockServer, Response};
+
+fn client(server: &MockServer) -> AocClient {
+    AocClient::new()
+        .with_base_url(&server.url())
+        .with_session_cookie("abc123")
+        .with_timeout(Duration::from_millis(500))
+}
+
+#[test]
+fn get_input_should_return_body_on_success() {
+    let server = MockServer::start(|_| Response::new(200, "3   4\n4   3\n"));
+
+    assert_eq!("3   4\n4   3\n", client(&server).get_input(2024, 1).unwrap());
+}
+
+#[test]
+fn get_input_should_request_input_url_with_session_cookie() {
+    let server = MockServer::start(|_| Response::new(200, "input"));
+
+    client(&server).get_input(2024, 6).unwrap();
+
+    let requests = server.requests();
+
+    assert_eq!(1, requests.len());
+    assert_eq!("GET", requests[0].method);
+    assert_eq!("/2024/day/6/input", requests[0].path);
+    assert_eq!(Some("session=abc123"), requests[0].header("Cookie"));
+}
+
+#[test]
+fn get_input_should_notify_observer_before_downloading() {
+    let server = MockServer::start(|_| Response::new(200, "input"));
+    let observer = Arc::new(RecordingObserver::new());
+
+    client(&server).with_observer(observer.clone()).get_input(2024, 6).unwrap();
+
+    assert_eq!(vec![InputEvent::DownloadingInput { year: 2024, day: 6 }], observer.events());
+}
+
+#[test]
+fn get_input_should_return_puzzle_not_unlocked_on_404() {
+    let server = MockServer::start(|_| Response::new(
+        404, "Please don't repeatedly request this endpoint before it unlocks!"));
+
+    assert!(matches!(
+        client(&server).get_input(2024, 25),
+        Err(InputError::PuzzleNotUnlocked(2024, 25))));
+}
+
+#[test]
+fn get_input_should_return_invalid_session_cookie_on_400() {
+    let server = MockServer::start(|_| Response::new(
+        400, "Puzzle inputs differ by user.  Please log in to get your puzzle input."));
+
+    assert!(matches!(
+        client(&server).get_input(2024, 1),
+        Err(InputError::InvalidSessionCookie)));
+}
+
+#[test]
+fn get_input_should_return_server_error_on_500() {
+    let server = MockServer::start(|_| Response::new(500, "Internal Server Error"));
+
+    assert!(matches!(
+        client(&server).get_input(2024, 1),
+        Err(InputError::ServerError(500, 2024, 1))));
+}
+
+#[test]
+fn get_input_should_return_timeout_on_slow_response() {
+    let server = MockServer::start(|_| {
+        Response::new(200, "too late").delayed(Duration::from_secs(2))
+    });
+
+    assert!(matches!(
+        client(&server).get_input(2024, 1),
+        Err(InputError::Timeout(2024, 1))));
+}
+
+#[test]
+fn with_base_url_should_ignore_trailing_slash() {
+    let server = MockServer::start(|_| Response::new(200, "input"));
+
+    let client = AocClient::new()
+        .with_base_url(&format!("{}/", server.url()))
+        .with_session_cookie("abc123");
+
+    assert_eq!(server.url(), client.base_url());
+    assert_eq!("input", client.get_input(2024, 1).unwrap());
+}
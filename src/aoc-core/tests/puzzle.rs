@@ -0,0 +1,113 @@
+mod common;
+
+use aoc_core::{
+    input_source::{AocClient, DiskCache},
+    puzzle::{Puzzle, PuzzleStore},
+};
+use common::{MockServer, Response, TempDir};
+
+const DAY_1: &str = include_str!("fixtures/2024_day_1.html");
+const DAY_6_PART_1: &str = include_str!("fixtures/2024_day_6_part_1.html");
+
+const DAY_1_EXAMPLE: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+fn store(server: &MockServer, root: &TempDir) -> PuzzleStore {
+    PuzzleStore::new(
+        DiskCache::new(root.path()),
+        AocClient::new().with_base_url(&server.url()).with_session_cookie("abc123"))
+}
+
+#[test]
+fn from_html_should_extract_examples_of_both_parts() {
+    let puzzle = Puzzle::from_html(DAY_1);
+
+    assert_eq!(2, puzzle.examples().len());
+    assert_eq!(Some(DAY_1_EXAMPLE), puzzle.example(1));
+    assert_eq!(Some(DAY_1_EXAMPLE), puzzle.example(2));
+}
+
+#[test]
+fn from_html_should_extract_answers_of_both_parts() {
+    let puzzle = Puzzle::from_html(DAY_1);
+
+    assert_eq!(2, puzzle.parts());
+    assert_eq!(Some("11"), puzzle.answer(1));
+    assert_eq!(Some("31"), puzzle.answer(2));
+}
+
+#[test]
+fn from_html_should_only_extract_first_part_if_unsolved() {
+    let puzzle = Puzzle::from_html(DAY_6_PART_1);
+
+    assert_eq!(1, puzzle.parts());
+    assert_eq!(2, puzzle.examples().len());
+    assert_eq!(Some("41"), puzzle.answer(1));
+    assert_eq!(None, puzzle.answer(2));
+    assert!(puzzle.example(1).unwrap().contains(".#..^....."));
+}
+
+#[test]
+fn get_puzzle_should_download_and_cache_examples() {
+    let server = MockServer::start(|_| Response::new(200, DAY_1));
+    let root = TempDir::new("puzzle-download");
+
+    let puzzle = store(&server, &root).get_puzzle(2024, 1).unwrap();
+
+    let puzzle_dir = root.join("2024").join("1");
+
+    assert_eq!(Some("11"), puzzle.answer(1));
+    assert_eq!("/2024/day/1", server.requests()[0].path);
+    assert_eq!(DAY_1, std::fs::read_to_string(puzzle_dir.join("puzzle.html")).unwrap());
+    assert_eq!(
+        DAY_1_EXAMPLE,
+        std::fs::read_to_string(puzzle_dir.join("examples").join("1.txt")).unwrap());
+    assert_eq!(
+        "31",
+        std::fs::read_to_string(puzzle_dir.join("examples").join("answer_2.txt")).unwrap());
+}
+
+#[test]
+fn get_puzzle_should_read_cached_puzzle() {
+    let server = MockServer::start(|_| Response::new(200, DAY_6_PART_1));
+    let root = TempDir::new("puzzle-cached");
+    let store = store(&server, &root);
+
+    store.get_puzzle(2024, 6).unwrap();
+    store.get_puzzle(2024, 6).unwrap();
+
+    assert_eq!(1, server.requests().len());
+}
+
+#[test]
+fn refresh_puzzle_should_replace_cached_puzzle() {
+    let server = MockServer::start(|_| Response::new(200, DAY_1));
+    let root = TempDir::new("puzzle-refresh");
+    let puzzle_dir = root.join("2024").join("1");
+
+    std::fs::create_dir_all(puzzle_dir.join("examples")).unwrap();
+    std::fs::write(puzzle_dir.join("puzzle.html"), DAY_6_PART_1).unwrap();
+    std::fs::write(puzzle_dir.join("examples").join("9.txt"), "stale").unwrap();
+
+    let store = store(&server, &root);
+
+    assert_eq!(None, store.get_puzzle(2024, 1).unwrap().answer(2));
+    assert_eq!(Some("31"), store.refresh_puzzle(2024, 1).unwrap().answer(2));
+    assert!(!puzzle_dir.join("examples").join("9.txt").exists());
+}
+
+#[test]
+fn get_example_should_return_example_and_answer() {
+    let server = MockServer::start(|_| Response::new(200, DAY_1));
+    let root = TempDir::new("puzzle-example");
+    let store = store(&server, &root);
+
+    assert_eq!(DAY_1_EXAMPLE, store.get_example(2024, 1, 1).unwrap());
+    assert_eq!(Some("11".to_string()), store.get_example_answer(2024, 1, 1).unwrap());
+    assert!(store.get_example(2024, 1, 3).is_err());
+}
@@ -0,0 +1,453 @@
+mod table;
+
+use std::{process::ExitCode, sync::Arc};
+
+use aoc_core::{
+    answer_store::{AnswerStore, Verification},
+    bench::{BaselineStore, BenchOptions},
+    input_source::{DiskCache, InputCache, InputSource},
+    observer::{InputObserver, JsonObserver, PrintObserver, SilentObserver},
+    solution::Solver,
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use table::Table;
+
+/// Runs the solutions of Advent Of Code 2024.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// How reading, downloading and saving the inputs is reported on stderr.
+    #[arg(long, value_enum, global = true, default_value_t = Events::Silent)]
+    events: Events,
+}
+
+/// The output of the input events.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+enum Events {
+    /// Reports nothing.
+    Silent,
+
+    /// Reports every event as text.
+    Verbose,
+
+    /// Reports every event as a line of JSON.
+    Json,
+}
+
+impl Events {
+    fn observer(self) -> Arc<dyn InputObserver> {
+        match self {
+            Events::Silent => Arc::new(SilentObserver),
+            Events::Verbose => Arc::new(PrintObserver),
+            Events::Json => Arc::new(JsonObserver),
+        }
+    }
+}
+
+/// The days whose solutions are used.
+#[derive(Debug, Args)]
+struct DaySelection {
+    /// The days to use.
+    #[arg(required_unless_present = "all")]
+    days: Vec<u32>,
+
+    /// Uses the solutions of all days.
+    #[arg(long, conflicts_with = "days")]
+    all: bool,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Runs the solutions of the given days on the puzzle inputs.
+    Run {
+        #[command(flatten)]
+        selection: DaySelection,
+
+        /// Runs only the given part.
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+    },
+
+    /// Benchmarks the solutions of the given days on the puzzle inputs and
+    /// compares them with the saved baselines.
+    Bench {
+        #[command(flatten)]
+        selection: DaySelection,
+
+        /// The number of measured runs of every stage.
+        #[arg(long, default_value_t = BenchOptions::default().runs)]
+        runs: usize,
+
+        /// The number of runs of every stage before the measurement starts.
+        #[arg(long, default_value_t = BenchOptions::default().warmup)]
+        warmup: usize,
+
+        /// The slowdown of the median in percent that is flagged as regression.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// Saves the results as the new baselines.
+        #[arg(long)]
+        save_baseline: bool,
+    },
+
+    /// Reruns the solutions on the cached puzzle inputs and compares the
+    /// answers with the known-good answers in `answers.toml`.
+    Verify {
+        /// The days to verify. All days are verified, if none is given.
+        days: Vec<u32>,
+
+        /// Records the answers of parts that have no known-good answer yet.
+        #[arg(long)]
+        record: bool,
+    },
+}
+
+/// Returns the solvers of all days.
+fn solvers() -> Vec<Box<dyn Solver>> {
+    vec![
+        Box::new(day_1::Day1),
+        Box::new(day_2::Day2),
+        Box::new(day_3::Day3),
+        Box::new(day_4::Day4),
+        Box::new(day_5::Day5),
+        Box::new(day_6::Day6),
+        Box::new(day_7::Day7),
+    ]
+}
+
+/// Returns the solvers of the selected days or of all days, if `all` is set.
+fn select_solvers(selection: &DaySelection) -> anyhow::Result<Vec<Box<dyn Solver>>> {
+    if selection.all {
+        return Ok(solvers());
+    }
+
+    selection.days.iter()
+        .map(|&day| {
+            solvers().into_iter()
+                .find(|solver| solver.day() == day)
+                .ok_or_else(|| anyhow::anyhow!("There is no solution for day {}.", day))
+        })
+        .collect()
+}
+
+fn run(selection: &DaySelection, part: Option<u32>, observer: Arc<dyn InputObserver>)
+    -> anyhow::Result<bool>
+{
+    let solvers = select_solvers(selection)?;
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut table = Table::new(&["Day", "Part", "Answer", "Parse", "Time"]);
+    let mut success = true;
+
+    for solver in solvers {
+        let Some(puzzle_input) = get_input(solver.as_ref(), observer.clone()) else {
+            success = false;
+            continue;
+        };
+
+        let run = match solver.run(&puzzle_input, &parts) {
+            Ok(run) => run,
+            Err(e) => {
+                eprintln!("Skipping day {}: {}", solver.day(), e);
+                success = false;
+                continue;
+            }
+        };
+
+        for result in run.results {
+            table.add_row(vec![
+                solver.day().to_string(),
+                result.part.to_string(),
+                result.answer,
+                format!("{:?}", run.parse_time),
+                format!("{:?}", result.time),
+            ]);
+        }
+    }
+
+    if !table.is_empty() {
+        print!("{}", table);
+    }
+
+    Ok(success)
+}
+
+fn bench(
+    selection: &DaySelection,
+    options: &BenchOptions,
+    threshold: f64,
+    save_baseline: bool,
+    observer: Arc<dyn InputObserver>,
+) -> anyhow::Result<bool> {
+    let solvers = select_solvers(selection)?;
+    let store = BaselineStore::data_dir()?;
+
+    let mut table = Table::new(&["Day", "Stage", "Min", "Median", "Mean", "Stddev", "Baseline"]);
+    let mut success = true;
+
+    for solver in solvers {
+        let Some(puzzle_input) = get_input(solver.as_ref(), observer.clone()) else {
+            success = false;
+            continue;
+        };
+
+        let report = match solver.bench(&puzzle_input, options) {
+            Ok(report) => report,
+            Err(e) => {
+                eprintln!("Skipping day {}: {}", solver.day(), e);
+                success = false;
+                continue;
+            }
+        };
+        let baseline = store.load(report.year, report.day)?;
+
+        let regressions = baseline.as_ref()
+            .map(|baseline| report.regressions(baseline, threshold / 100.0))
+            .unwrap_or_default();
+
+        let changes = baseline.as_ref()
+            .map(|baseline| report.changes(baseline))
+            .unwrap_or_default();
+
+        for stage in &report.stages {
+            let change = changes.iter()
+                .find(|(name, _)| *name == stage.name)
+                .and_then(|(_, change)| *change)
+                .map(|change| {
+                    let flag = if regressions.iter().any(|r| r.stage == stage.name) {
+                        " REGRESSION"
+                    } else {
+                        ""
+                    };
+
+                    format!("{:+.1}%{}", change * 100.0, flag)
+                })
+                .unwrap_or_else(|| "-".to_string());
+
+            table.add_row(vec![
+                report.day.to_string(),
+                stage.name.clone(),
+                format!("{:?}", stage.stats.min),
+                format!("{:?}", stage.stats.median),
+                format!("{:?}", stage.stats.mean),
+                format!("{:?}", stage.stats.stddev),
+                change,
+            ]);
+        }
+
+        if !regressions.is_empty() {
+            success = false;
+        }
+
+        if save_baseline {
+            store.save(&report)?;
+        }
+    }
+
+    if !table.is_empty() {
+        print!("{}", table);
+    }
+
+    Ok(success)
+}
+
+fn verify(days: Vec<u32>, record: bool, observer: Arc<dyn InputObserver>)
+    -> anyhow::Result<bool>
+{
+    let selection = DaySelection { all: days.is_empty(), days };
+    let solvers = select_solvers(&selection)?;
+    let cache = DiskCache::data_dir()?.with_observer(observer);
+    let mut store = AnswerStore::data_dir()?;
+
+    let mut table = Table::new(&["Day", "Part", "Answer", "Expected", "Result"]);
+    let mut success = true;
+    let mut recorded = false;
+
+    for solver in solvers {
+        let (year, day) = (solver.year(), solver.day());
+
+        if !cache.contains(year, day) {
+            eprintln!("Skipping day {}: The puzzle input is not cached.", day);
+            continue;
+        }
+
+        let puzzle_input = cache.get_input(year, day)?;
+        let run = match solver.run(&puzzle_input, &[1, 2]) {
+            Ok(run) => run,
+            Err(e) => {
+                eprintln!("Skipping day {}: {}", day, e);
+                success = false;
+                continue;
+            }
+        };
+
+        for result in run.results {
+            let (expected, outcome) = match store.verify(year, day, result.part, &result.answer) {
+                Verification::Match => (result.answer.clone(), "ok"),
+                Verification::Mismatch(expected) => {
+                    success = false;
+                    (expected, "MISMATCH")
+                }
+                Verification::Unknown if record => {
+                    store.set(year, day, result.part, &result.answer);
+                    recorded = true;
+                    (result.answer.clone(), "recorded")
+                }
+                Verification::Unknown => ("-".to_string(), "unknown"),
+            };
+
+            table.add_row(vec![
+                day.to_string(),
+                result.part.to_string(),
+                result.answer,
+                expected,
+                outcome.to_string(),
+            ]);
+        }
+    }
+
+    if recorded {
+        store.save()?;
+    }
+
+    if !table.is_empty() {
+        print!("{}", table);
+    }
+
+    Ok(success)
+}
+
+/// Returns the puzzle input for the `solver` or prints why it is skipped.
+fn get_input(solver: &dyn Solver, observer: Arc<dyn InputObserver>) -> Option<String> {
+    match aoc_core::get_input_with_observer(solver.year(), solver.day(), observer) {
+        Ok(puzzle_input) => Some(puzzle_input),
+        Err(e) => {
+            eprintln!("Skipping day {}: {}", solver.day(), e);
+            None
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let observer = cli.events.observer();
+
+    let result = match cli.command {
+        Command::Run { selection, part } => run(&selection, part, observer),
+        Command::Bench { selection, runs, warmup, threshold, save_baseline } => {
+            bench(&selection, &BenchOptions { runs, warmup }, threshold, save_baseline, observer)
+        }
+        Command::Verify { days, record } => verify(days, record, observer),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solvers_should_be_unique_per_day() {
+        let days: Vec<u32> = solvers().iter().map(|solver| solver.day()).collect();
+
+        assert_eq!(vec![1, 2, 3, 4, 5, 6, 7], days);
+    }
+
+    #[test]
+    fn select_solvers_should_keep_order_of_days() {
+        let selection = DaySelection { days: vec![6, 2], all: false };
+        let days: Vec<u32> = select_solvers(&selection).unwrap()
+            .iter()
+            .map(|solver| solver.day())
+            .collect();
+
+        assert_eq!(vec![6, 2], days);
+    }
+
+    #[test]
+    fn select_solvers_should_fail_for_unknown_day() {
+        assert!(select_solvers(&DaySelection { days: vec![25], all: false }).is_err());
+    }
+
+    #[test]
+    fn cli_should_parse_run_with_part() {
+        let cli = Cli::try_parse_from(["aoc", "run", "5", "--part", "2"]).unwrap();
+
+        match cli.command {
+            Command::Run { selection, part } => {
+                assert_eq!(vec![5], selection.days);
+                assert!(!selection.all);
+                assert_eq!(Some(2), part);
+            }
+            command => panic!("Unexpected command {:?}", command),
+        }
+    }
+
+    #[test]
+    fn cli_should_reject_part_3() {
+        assert!(Cli::try_parse_from(["aoc", "run", "5", "--part", "3"]).is_err());
+    }
+
+    #[test]
+    fn cli_should_reject_days_together_with_all() {
+        assert!(Cli::try_parse_from(["aoc", "run", "5", "--all"]).is_err());
+    }
+
+    #[test]
+    fn cli_should_parse_bench_with_defaults() {
+        let cli = Cli::try_parse_from(["aoc", "bench", "--all"]).unwrap();
+
+        match cli.command {
+            Command::Bench { selection, runs, warmup, threshold, save_baseline } => {
+                assert!(selection.all);
+                assert_eq!(BenchOptions::default(), BenchOptions { runs, warmup });
+                assert_eq!(10.0, threshold);
+                assert!(!save_baseline);
+            }
+            command => panic!("Unexpected command {:?}", command),
+        }
+    }
+
+    #[test]
+    fn cli_should_parse_verify_without_days() {
+        let cli = Cli::try_parse_from(["aoc", "verify", "--record"]).unwrap();
+
+        match cli.command {
+            Command::Verify { days, record } => {
+                assert!(days.is_empty());
+                assert!(record);
+            }
+            command => panic!("Unexpected command {:?}", command),
+        }
+    }
+
+    #[test]
+    fn cli_should_be_silent_by_default() {
+        let cli = Cli::try_parse_from(["aoc", "run", "1"]).unwrap();
+
+        assert_eq!(Events::Silent, cli.events);
+    }
+
+    #[test]
+    fn cli_should_accept_events_after_subcommand() {
+        let cli = Cli::try_parse_from(["aoc", "run", "1", "--events", "json"]).unwrap();
+
+        assert_eq!(Events::Json, cli.events);
+    }
+}
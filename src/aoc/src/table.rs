@@ -0,0 +1,99 @@
+/// A table with a header that is printed with aligned columns.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    /// Creates a new `Table` without rows.
+    ///
+    /// # Arguments
+    ///
+    /// - `header` - The titles of the columns.
+    pub fn new(header: &[&str]) -> Self {
+        Self {
+            header: header.iter().map(|title| title.to_string()).collect(),
+            rows: vec![],
+        }
+    }
+
+    /// Adds a row to the table. Missing cells are left empty.
+    ///
+    /// # Arguments
+    ///
+    /// - `row` - The cells of the row.
+    pub fn add_row(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    /// Returns `true` if the table has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    fn column_widths(&self) -> Vec<usize> {
+        self.header.iter()
+            .enumerate()
+            .map(|(column, title)| {
+                self.rows.iter()
+                    .filter_map(|row| row.get(column))
+                    .map(|cell| cell.chars().count())
+                    .chain(std::iter::once(title.chars().count()))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let widths = self.column_widths();
+
+        let write_row = |f: &mut std::fmt::Formatter<'_>, cells: &[String]| {
+            let line = widths.iter()
+                .enumerate()
+                .map(|(column, width)| {
+                    let cell = cells.get(column).map(|cell| cell.as_str()).unwrap_or("");
+                    format!("{:<width$}", cell, width = width)
+                })
+                .collect::<Vec<_>>()
+                .join(" | ");
+
+            writeln!(f, "{}", line.trim_end())
+        };
+
+        write_row(f, &self.header)?;
+
+        let separator = widths.iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<_>>()
+            .join("-+-");
+
+        writeln!(f, "{}", separator)?;
+
+        for row in &self.rows {
+            write_row(f, row)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_should_align_columns() {
+        let mut table = Table::new(&["Day", "Answer"]);
+
+        table.add_row(vec!["1".to_string(), "1830467".to_string()]);
+        table.add_row(vec!["12".to_string(), "5".to_string()]);
+
+        assert_eq!(
+            "Day | Answer\n----+--------\n1   | 1830467\n12  | 5\n",
+            table.to_string());
+    }
+}
@@ -1,24 +1,27 @@
 mod parser;
 
-use anyhow::Result;
+use aoc_core::solution::{Solution, SolutionError};
 
-fn main() -> Result<()> {
-    let puzzle_input = aoc_core::get_input(2024, 1)?;
+/// The solution of day 1: Historian Hysteria.
+pub struct Day1;
 
-    let (location_ids_left, location_ids_right) =
-        parser::parse_location_ids(&puzzle_input);
+impl Solution for Day1 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 1;
 
-    let total_distance =
-        total_distance(location_ids_left.clone(), location_ids_right.clone());
+    type Input = (Vec<i32>, Vec<i32>);
 
-    println!("Total distance between the lists: {}", total_distance);
-
-    let total_similarity =
-        total_similarity(&location_ids_left, &location_ids_right);
+    fn parse(&self, puzzle_input: &str) -> Result<Self::Input, SolutionError> {
+        parser::parse_location_ids(puzzle_input)
+    }
 
-    println!("Total similarity between the lists: {}", total_similarity);
+    fn part1(&self, input: &Self::Input) -> String {
+        total_distance(input.0.clone(), input.1.clone()).to_string()
+    }
 
-    Ok(())
+    fn part2(&self, input: &Self::Input) -> String {
+        total_similarity(&input.0, &input.1).to_string()
+    }
 }
 
 fn total_similarity(location_ids_left: &[i32], location_ids_right: &[i32])
@@ -43,7 +46,7 @@ fn total_distance(
 
     let pairs: Vec<(i32, i32)> =
         location_ids_left.into_iter().zip(
-            location_ids_right.into_iter()
+            location_ids_right
         ).collect();
 
     pairs.iter().map(|&pair| distance(pair)).sum()
@@ -57,6 +60,7 @@ fn distance(pair: (i32, i32)) -> i32 {
     }
 }
 
+#[cfg(test)]
 const TEST_DATA: &str =
 r"3   4
 4   3
@@ -67,7 +71,9 @@ r"3   4
 
 #[cfg(test)]
 mod tests {
-    use crate::{distance, parser::parse_location_ids, similarity, total_distance, total_similarity, TEST_DATA};
+    use aoc_core::solution::Solution;
+
+    use crate::{Day1, distance, parser::parse_location_ids, similarity, total_distance, total_similarity, TEST_DATA};
 
     #[test]
     fn distance_should_return_2_for_pair_1_and_3() {
@@ -82,21 +88,21 @@ mod tests {
     #[test]
     fn total_distance_of_test_data_should_be_11() {
         let (location_ids_left, location_ids_right) =
-            parse_location_ids(TEST_DATA);
+            parse_location_ids(TEST_DATA).unwrap();
 
         assert_eq!(11, total_distance(location_ids_left, location_ids_right));
     }
 
     #[test]
     fn similarity_for_3_and_test_data_should_be_9() {
-        let (_, location_ids_right) = parse_location_ids(TEST_DATA);
+        let (_, location_ids_right) = parse_location_ids(TEST_DATA).unwrap();
 
         assert_eq!(9, similarity(3, &location_ids_right));
     }
 
     #[test]
     fn similarity_for_2_and_test_data_should_be_0() {
-        let (_, location_ids_right) = parse_location_ids(TEST_DATA);
+        let (_, location_ids_right) = parse_location_ids(TEST_DATA).unwrap();
 
         assert_eq!(0, similarity(2, &location_ids_right));
     }
@@ -104,10 +110,23 @@ mod tests {
     #[test]
     fn total_similarity_for_test_data_should_be_31() {
         let (location_ids_left, location_ids_right) =
-            parse_location_ids(TEST_DATA);
+            parse_location_ids(TEST_DATA).unwrap();
 
         assert_eq!(
             31,
             total_similarity(&location_ids_left, &location_ids_right));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn day1_should_solve_both_parts_of_example_data() {
+        let input = Day1.parse(TEST_DATA).unwrap();
+
+        assert_eq!("11", Day1.part1(&input));
+        assert_eq!("31", Day1.part2(&input));
+    }
+
+    #[test]
+    fn day1_parse_should_fail_for_malformed_line() {
+        assert!(Day1.parse("3   4\n4 x").is_err());
+    }
+}
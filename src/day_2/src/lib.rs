@@ -1,27 +1,31 @@
 mod parser;
 mod report_checker;
 
-use anyhow::Result;
+use aoc_core::solution::{Solution, SolutionError};
 
-fn main() -> Result<()> {
-    let puzzle_input = aoc_core::get_input(2024, 2)?;
+/// The solution of day 2: Red-Nosed Reports.
+pub struct Day2;
 
-    let reports = parser::parse_reports(&puzzle_input);
-    let number_of_safe_reports = count_safe_reports(&reports);
+impl Solution for Day2 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 2;
 
-    println!("Number of safe reports: {}", number_of_safe_reports);
+    type Input = Vec<Vec<i32>>;
 
-    let number_of_safe_reports = 
-        count_safe_reports_with_problem_dampener_active(&reports);
+    fn parse(&self, puzzle_input: &str) -> Result<Self::Input, SolutionError> {
+        parser::parse_reports(puzzle_input)
+    }
 
-    println!(
-        "Number of safe reports (problem dampener active): {}",
-        number_of_safe_reports);
+    fn part1(&self, input: &Self::Input) -> String {
+        count_safe_reports(input).to_string()
+    }
 
-    Ok(())
+    fn part2(&self, input: &Self::Input) -> String {
+        count_safe_reports_with_problem_dampener_active(input).to_string()
+    }
 }
 
-fn count_safe_reports_with_problem_dampener_active(reports: &Vec<Vec<i32>>)
+fn count_safe_reports_with_problem_dampener_active(reports: &[Vec<i32>])
     -> usize
 {
     let mut number_of_safe_reports = 0;
@@ -37,9 +41,9 @@ fn count_safe_reports_with_problem_dampener_active(reports: &Vec<Vec<i32>>)
     number_of_safe_reports
 }
 
-fn is_safe_with_problem_dampener(report: &Vec<i32>) -> bool {
+fn is_safe_with_problem_dampener(report: &[i32]) -> bool {
     for i in 0 .. report.len() {
-        let mut report_with_level_removed = report.clone();
+        let mut report_with_level_removed = report.to_vec();
 
         report_with_level_removed.remove(i);
 
@@ -51,7 +55,7 @@ fn is_safe_with_problem_dampener(report: &Vec<i32>) -> bool {
     false
 }
 
-fn count_safe_reports(reports: &Vec<Vec<i32>>) -> usize {
+fn count_safe_reports(reports: &[Vec<i32>]) -> usize {
     reports.iter()
         .map(|report| report_checker::is_safe_report(report))
         .filter(|is_safe| *is_safe)
@@ -60,14 +64,16 @@ fn count_safe_reports(reports: &Vec<Vec<i32>>) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use crate::{count_safe_reports, count_safe_reports_with_problem_dampener_active, is_safe_with_problem_dampener};
+    use aoc_core::solution::Solution;
+
+    use crate::{count_safe_reports, count_safe_reports_with_problem_dampener_active, is_safe_with_problem_dampener, Day2};
 
     #[test]
     fn count_safe_reports_should_return_2_for_test_data() {
         assert_eq!(
             2,
             count_safe_reports(
-                &vec![
+                &[
                     vec![7, 6, 4, 2, 1],
                     vec![1, 2, 7, 8, 9],
                     vec![9, 7, 6, 2, 1],
@@ -81,22 +87,22 @@ mod tests {
 
     #[test]
     fn is_safe_with_problem_dampener_should_return_false_for_1_2_7_8_9() {
-        assert!(!is_safe_with_problem_dampener(&vec![1, 2, 7, 8, 9]));
+        assert!(!is_safe_with_problem_dampener(&[1, 2, 7, 8, 9]));
     }
 
     #[test]
     fn is_safe_with_problem_dampener_should_return_false_for_9_7_6_2_1() {
-        assert!(!is_safe_with_problem_dampener(&vec![9, 7, 6, 2, 1]));
+        assert!(!is_safe_with_problem_dampener(&[9, 7, 6, 2, 1]));
     }
 
     #[test]
     fn is_safe_with_problem_dampener_should_return_true_for_1_3_2_4_5() {
-        assert!(is_safe_with_problem_dampener(&vec![1, 3, 2, 4, 5]));
+        assert!(is_safe_with_problem_dampener(&[1, 3, 2, 4, 5]));
     }
 
     #[test]
     fn is_safe_with_problem_dampener_should_return_true_for_8_6_4_4_1() {
-        assert!(is_safe_with_problem_dampener(&vec![8, 6, 4, 4, 1]));
+        assert!(is_safe_with_problem_dampener(&[8, 6, 4, 4, 1]));
     }
 
     #[test]
@@ -104,7 +110,7 @@ mod tests {
         assert_eq!(
             4,
             count_safe_reports_with_problem_dampener_active(
-                &vec![
+                &[
                     vec![7, 6, 4, 2, 1],
                     vec![1, 2, 7, 8, 9],
                     vec![9, 7, 6, 2, 1],
@@ -115,4 +121,9 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn day2_parse_should_fail_for_malformed_line() {
+        assert!(Day2.parse("7 6 4 2 1\n1 2 x 8 9").is_err());
+    }
 }
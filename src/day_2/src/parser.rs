@@ -1,10 +1,14 @@
-pub fn parse_reports(puzzle_input: &str) -> Vec<Vec<i32>> {
-    puzzle_input.lines().map(|line| parse_report(line)).collect()
+use aoc_core::solution::SolutionError;
+
+pub fn parse_reports(puzzle_input: &str) -> Result<Vec<Vec<i32>>, SolutionError> {
+    puzzle_input.lines().map(parse_report).collect()
 }
 
-fn parse_report(line: &str) -> Vec<i32> {
+fn parse_report(line: &str) -> Result<Vec<i32>, SolutionError> {
     line.split_whitespace()
-        .map(|level| level.parse::<i32>().unwrap()).collect()
+        .map(|level| level.parse::<i32>())
+        .collect::<Result<_, _>>()
+        .map_err(|_| SolutionError::InvalidInput(format!("'{}' is not a report of levels.", line)))
 }
 
 #[cfg(test)]
@@ -13,7 +17,12 @@ mod tests {
 
     #[test]
     fn parse_report_should_return_vec_of_i32() {
-        assert_eq!(vec![7,6,4,2,1], parse_report("7 6 4 2 1"));
+        assert_eq!(Ok(vec![7,6,4,2,1]), parse_report("7 6 4 2 1"));
+    }
+
+    #[test]
+    fn parse_report_should_fail_for_invalid_level() {
+        assert!(parse_report("7 6 x 2 1").is_err());
     }
 
 const TEST_DATA: &str = r"7 6 4 2 1
@@ -26,14 +35,14 @@ const TEST_DATA: &str = r"7 6 4 2 1
     #[test]
     fn parse_reports_should_parse_test_data() {
         assert_eq!(
-            vec![
+            Ok(vec![
                 vec![7, 6, 4, 2, 1],
                 vec![1, 2, 7, 8, 9],
                 vec![9, 7, 6, 2, 1],
                 vec![1, 3, 2, 4, 5],
                 vec![8, 6, 4, 4, 1],
                 vec![1, 3, 6, 7, 9],
-            ],
+            ]),
             parse_reports(TEST_DATA)
         )
     }
@@ -46,31 +46,31 @@ mod tests {
 
     #[test]
     fn is_safe_report_should_return_true_for_7_6_4_2_1() {
-        assert!(is_safe_report(&vec![7, 6, 4, 2, 1]));
+        assert!(is_safe_report(&[7, 6, 4, 2, 1]));
     }
 
     #[test]
     fn is_safe_report_should_return_false_for_1_2_7_8_9() {
-        assert!(!is_safe_report(&vec![1, 2, 7, 8, 9]));
+        assert!(!is_safe_report(&[1, 2, 7, 8, 9]));
     }
 
     #[test]
     fn is_safe_report_should_return_false_for_9_7_6_2_1() {
-        assert!(!is_safe_report(&vec![9, 7, 6, 2, 1]));
+        assert!(!is_safe_report(&[9, 7, 6, 2, 1]));
     }
 
     #[test]
     fn is_safe_report_should_return_false_for_1_3_2_4_5() {
-        assert!(!is_safe_report(&vec![1, 3, 2, 4, 5]));
+        assert!(!is_safe_report(&[1, 3, 2, 4, 5]));
     }
 
     #[test]
     fn is_safe_report_should_return_false_for_8_6_4_4_1() {
-        assert!(!is_safe_report(&vec![8, 6, 4, 4, 1]));
+        assert!(!is_safe_report(&[8, 6, 4, 4, 1]));
     }
 
     #[test]
     fn is_safe_report_should_return_true_for_1_3_6_7_9() {
-        assert!(is_safe_report(&vec![1, 3, 6, 7, 9]));
+        assert!(is_safe_report(&[1, 3, 6, 7, 9]));
     }
 }
@@ -0,0 +1,89 @@
+mod instructions_parser;
+
+use aoc_core::solution::{Solution, SolutionError};
+use instructions_parser::Instruction;
+
+/// The solution of day 3: Mull It Over.
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 3;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, puzzle_input: &str) -> Result<Self::Input, SolutionError> {
+        instructions_parser::parse(puzzle_input).ok_or_else(|| {
+            SolutionError::InvalidInput("The puzzle input has no instructions.".to_string())
+        })
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        sum_uncorrupted_mul_instructions(input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        sum_mul(input).to_string()
+    }
+}
+
+fn sum_uncorrupted_mul_instructions(instructions: &[Instruction]) -> i32 {
+    instructions.iter()
+        .map(|instruction| match instruction {
+            Instruction::Mul(num1, num2) => num1 * num2,
+            _ => 0,
+        })
+        .sum()
+}
+
+fn sum_mul(instructions: &[Instruction]) -> i32 {
+    let mut sum = 0;
+    let mut mul_enabled = true;
+
+    for instruction in instructions.iter() {
+        match instruction {
+            Instruction::Do => mul_enabled = true,
+            Instruction::Dont => mul_enabled = false,
+            Instruction::Mul(num1, num2) => {
+                if mul_enabled {
+                    sum += num1 * num2
+                }
+            }
+        }
+
+    }
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::Solution;
+
+    use crate::{instructions_parser::parse, sum_mul, sum_uncorrupted_mul_instructions, Day3};
+
+    const TEST_DATA: &str = r"xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+
+    #[test]
+    fn sum_uncorrupted_mul_instructions_should_return_161_for_test_data() {
+        assert_eq!(161, sum_uncorrupted_mul_instructions(&parse(TEST_DATA).unwrap()));
+    }
+
+    const TEST_DATA_2: &str = r"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn sum_mul_should_return_48_for_test_data() {
+        assert_eq!(48, sum_mul(&parse(TEST_DATA_2).unwrap()));
+    }
+
+    #[test]
+    fn day3_should_solve_both_parts_of_example_data() {
+        assert_eq!("161", Day3.part1(&Day3.parse(TEST_DATA).unwrap()));
+        assert_eq!("48", Day3.part2(&Day3.parse(TEST_DATA_2).unwrap()));
+    }
+
+    #[test]
+    fn day3_parse_should_fail_without_instructions() {
+        assert!(Day3.parse("mul(4*").is_err());
+    }
+}
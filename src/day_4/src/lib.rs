@@ -0,0 +1,208 @@
+use aoc_core::{
+    primitives::{Direction, Rect},
+    solution::{Solution, SolutionError},
+    text_map::TextMap,
+};
+
+/// The solution of day 4: Ceres Search.
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 4;
+
+    type Input = TextMap;
+
+    fn parse(&self, puzzle_input: &str) -> Result<Self::Input, SolutionError> {
+        TextMap::try_from(puzzle_input)
+            .map_err(|e| SolutionError::InvalidInput(e.to_string()))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        find_all_xmas(input).map_or(0, |occurences| occurences.len()).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        find_all_x_shaped_xmas(input).map_or(0, |occurences| occurences.len()).to_string()
+    }
+}
+
+fn find_all_xmas(text_map: &TextMap) -> Option<Vec<Rect>> {
+    let occurences: Vec<Rect> = text_map.find_word("XMAS", &Direction::ALL)
+        .into_iter()
+        .map(|occurence| occurence.rect)
+        .collect();
+
+    if occurences.is_empty() {
+        None
+    } else {
+        Some(occurences)
+    }
+}
+
+fn find_all_x_shaped_xmas(text_map: &TextMap) -> Option<Vec<Rect>> {
+    let pattern = TextMap::try_from("M.S\n.A.\nM.S").unwrap();
+    let occurences: Vec<Rect> = text_map.find_pattern_in_any_orientation(&pattern, '.').collect();
+
+    if occurences.is_empty() {
+        None
+    } else {
+        Some(occurences)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{primitives::Rect, solution::Solution, text_map::TextMap};
+
+    use crate::{Day4, find_all_x_shaped_xmas, find_all_xmas};
+
+const TEST_DATA_NORTH: &str = r"..S.......
+..A.......
+..M.......
+..X.......";
+
+    #[test]
+    fn find_all_xmas_should_find_northern_xmas() {
+        let text_map = TextMap::try_from(TEST_DATA_NORTH).unwrap();
+
+        assert_eq!(
+            vec![Rect {x1: 2, y1: 3, x2: 2, y2: 0}],
+            find_all_xmas(&text_map).unwrap());
+    }
+
+    const TEST_DATA_NORTH_EAST: &str = r".....S....
+....A.....
+...M......
+..X.......";
+    
+    #[test]
+    fn find_all_xmas_should_find_north_eastern_xmas() {
+        let text_map = TextMap::try_from(TEST_DATA_NORTH_EAST).unwrap();
+
+        assert_eq!(
+            vec![Rect {x1: 2, y1: 3, x2: 5, y2: 0}],
+            find_all_xmas(&text_map).unwrap());
+    }
+
+    const TEST_DATA_EAST: &str = r"......XMAS
+..........
+..........
+..........";
+    
+    #[test]
+    fn find_all_xmas_should_find_eastern_xmas() {
+        let text_map = TextMap::try_from(TEST_DATA_EAST).unwrap();
+
+        assert_eq!(
+            vec![Rect {x1: 6, y1: 0, x2: 9, y2: 0}],
+            find_all_xmas(&text_map).unwrap());
+    }
+
+    const TEST_DATA_SOUTH_EAST: &str = r"X.........
+.M........
+..A.......
+...S......";
+    
+    #[test]
+    fn find_all_xmas_should_find_south_eastern_xmas() {
+        let text_map = TextMap::try_from(TEST_DATA_SOUTH_EAST).unwrap();
+
+        assert_eq!(
+            vec![Rect {x1: 0, y1: 0, x2: 3, y2: 3}],
+            find_all_xmas(&text_map).unwrap());
+    }
+
+    const TEST_DATA_SOUTH: &str = r".........X
+.........M
+.........A
+.........S";
+
+    #[test]
+    fn find_all_xmas_should_find_southern_xmas() {
+        let text_map = TextMap::try_from(TEST_DATA_SOUTH).unwrap();
+
+        assert_eq!(
+            vec![Rect {x1: 9, y1: 0, x2: 9, y2: 3}],
+            find_all_xmas(&text_map).unwrap());
+    }
+   
+    const TEST_DATA_SOUTH_WEST: &str = r"...X......
+..M.......
+.A........
+S.........";
+
+    #[test]
+    fn find_all_xmas_should_find_south_western_xmas() {
+        let text_map = TextMap::try_from(TEST_DATA_SOUTH_WEST).unwrap();
+
+        assert_eq!(
+            vec![Rect {x1: 3, y1: 0, x2: 0, y2: 3}],
+            find_all_xmas(&text_map).unwrap());
+    }
+
+    const TEST_DATA_WEST: &str = r"..........
+..........
+..........
+SAMX......";
+
+    #[test]
+    fn find_all_xmas_should_find_western_xmas() {
+        let text_map = TextMap::try_from(TEST_DATA_WEST).unwrap();
+
+        assert_eq!(
+            vec![Rect {x1: 3, y1: 3, x2: 0, y2: 3}],
+            find_all_xmas(&text_map).unwrap());
+    }
+
+    const TEST_DATA_NORTH_WEST: &str = r"S.........
+.A........
+..M.......
+...X......";
+    
+    #[test]
+    fn find_all_xmas_should_find_north_western_xmas() {
+        let text_map = TextMap::try_from(TEST_DATA_NORTH_WEST).unwrap();
+
+        assert_eq!(
+            vec![Rect {x1: 3, y1: 3, x2: 0, y2: 0}],
+            find_all_xmas(&text_map).unwrap());
+    }
+
+    const EXAMPLE_DATA: &str = r"MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+    #[test]
+    fn find_all_xmas_should_find_all_occurences_in_example_data() {
+        let text_map = TextMap::try_from(EXAMPLE_DATA).unwrap();
+
+        assert_eq!(
+            18,
+            find_all_xmas(&text_map).unwrap().len());
+    }
+
+    #[test]
+    fn find_all_x_shaped_xmas_should_find_all_occurences_in_example_data() {
+        let text_map = TextMap::try_from(EXAMPLE_DATA).unwrap();
+
+        assert_eq!(
+            9,
+            find_all_x_shaped_xmas(&text_map).unwrap().len());
+    }
+
+    #[test]
+    fn day4_should_solve_both_parts_of_example_data() {
+        let input = Day4.parse(EXAMPLE_DATA).unwrap();
+
+        assert_eq!("18", Day4.part1(&input));
+        assert_eq!("9", Day4.part2(&input));
+    }
+}
@@ -3,35 +3,37 @@ mod rule;
 
 use std::cmp::Ordering;
 
-use anyhow::Result;
+use aoc_core::solution::{Solution, SolutionError};
 use parser::parse_input;
 use rule::PageOrderingRule;
 
-fn main() -> Result<()> {
-    let puzzle_input = aoc_core::get_input(2024, 5)?;
+/// The solution of day 5: Print Queue.
+pub struct Day5;
 
-    let sum_of_middle_page_numbers =
-        sum_middle_page_numbers_of_valid_page_updates(&puzzle_input);
+impl Solution for Day5 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 5;
 
-    println!(
-        "The sum of middle page numbers of valid page updates is: {}",
-        sum_of_middle_page_numbers);
+    type Input = (Vec<PageOrderingRule>, Vec<Vec<i32>>);
 
-    let sum_of_middle_page_numbers_of_invalid_page_updates =
-        sum_middle_page_numbers_of_invalid_page_updates(&puzzle_input);
+    fn parse(&self, puzzle_input: &str) -> Result<Self::Input, SolutionError> {
+        parse_input(puzzle_input)
+    }
 
-    println!(
-        "The sum of middle page numbers of invalid page updates is: {}",
-        sum_of_middle_page_numbers_of_invalid_page_updates);
+    fn part1(&self, input: &Self::Input) -> String {
+        sum_middle_page_numbers_of_valid_page_updates(&input.0, &input.1).to_string()
+    }
 
-    Ok(())
+    fn part2(&self, input: &Self::Input) -> String {
+        sum_middle_page_numbers_of_invalid_page_updates(&input.0, &input.1).to_string()
+    }
 }
 
-fn sum_middle_page_numbers_of_valid_page_updates(puzzle_input: &str) -> i32 {
-    let (page_ordering_rules, all_page_updates) = parse_input(puzzle_input);
-
+fn sum_middle_page_numbers_of_valid_page_updates(
+    page_ordering_rules: &[PageOrderingRule], all_page_updates: &[Vec<i32>]
+) -> i32 {
     all_page_updates.iter()
-        .filter(|page_update| verify_safety_manual_update(page_update, &page_ordering_rules))
+        .filter(|page_update| verify_safety_manual_update(page_update, page_ordering_rules))
         .map(|page_update| get_middle_page_number(page_update))
         .sum()
 }
@@ -64,19 +66,19 @@ fn verify_page_ordering_rule(safety_manual_update: &[i32], page_ordering_rule: &
     }
 }
 
-fn sum_middle_page_numbers_of_invalid_page_updates(puzzle_input: &str) -> i32 {
-    let (page_ordering_rules, all_page_updates) = parse_input(puzzle_input);
-
+fn sum_middle_page_numbers_of_invalid_page_updates(
+    page_ordering_rules: &[PageOrderingRule], all_page_updates: &[Vec<i32>]
+) -> i32 {
     all_page_updates.iter()
-        .filter(|page_update| !verify_safety_manual_update(page_update, &page_ordering_rules))
+        .filter(|page_update| !verify_safety_manual_update(page_update, page_ordering_rules))
         .map(|page_update| {
-            let sorted = sort_by_page_order_rules(page_update, &page_ordering_rules);
+            let sorted = sort_by_page_order_rules(page_update, page_ordering_rules);
             get_middle_page_number(&sorted)
         })
         .sum()
 }
 
-fn sort_by_page_order_rules(safety_manual_update: &Vec<i32>, page_ordering_rules: &[PageOrderingRule]) -> Vec<i32> {
+fn sort_by_page_order_rules(safety_manual_update: &[i32], page_ordering_rules: &[PageOrderingRule]) -> Vec<i32> {
     let compare = |x: &i32, y: &i32| {
         let (x, y) = (*x, *y);
 
@@ -89,7 +91,7 @@ fn sort_by_page_order_rules(safety_manual_update: &Vec<i32>, page_ordering_rules
         }
     };
 
-    let mut sorted_safety_manual_update: Vec<i32> = safety_manual_update.clone();
+    let mut sorted_safety_manual_update: Vec<i32> = safety_manual_update.to_vec();
 
     sorted_safety_manual_update.sort_by(compare);
 
@@ -98,7 +100,9 @@ fn sort_by_page_order_rules(safety_manual_update: &Vec<i32>, page_ordering_rules
 
 #[cfg(test)]
 mod tests {
-    use crate::{get_middle_page_number, parser::parse_input, sum_middle_page_numbers_of_invalid_page_updates, sum_middle_page_numbers_of_valid_page_updates, verify_safety_manual_update};
+    use aoc_core::solution::Solution;
+
+    use crate::{Day5, get_middle_page_number, parser::parse_input, sum_middle_page_numbers_of_invalid_page_updates, sum_middle_page_numbers_of_valid_page_updates, verify_safety_manual_update};
 
     const EXAMPLE_DATA: &str = r"47|53
 97|13
@@ -131,58 +135,75 @@ mod tests {
 
     #[test]
     fn verify_safety_manual_update_should_return_true_for_first_updates_in_example_data() {
-        let (page_ordering_rules, all_page_updates) = parse_input(EXAMPLE_DATA);
+        let (page_ordering_rules, all_page_updates) = parse_input(EXAMPLE_DATA).unwrap();
 
         assert!(verify_safety_manual_update(&all_page_updates[0], &page_ordering_rules));
     }
 
     #[test]
     fn verify_safety_manual_update_should_return_true_for_second_updates_in_example_data() {
-        let (page_ordering_rules, all_page_updates) = parse_input(EXAMPLE_DATA);
+        let (page_ordering_rules, all_page_updates) = parse_input(EXAMPLE_DATA).unwrap();
 
         assert!(verify_safety_manual_update(&all_page_updates[1], &page_ordering_rules));
     }
 
     #[test]
     fn verify_safety_manual_update_should_return_true_for_third_updates_in_example_data() {
-        let (page_ordering_rules, all_page_updates) = parse_input(EXAMPLE_DATA);
+        let (page_ordering_rules, all_page_updates) = parse_input(EXAMPLE_DATA).unwrap();
 
         assert!(verify_safety_manual_update(&all_page_updates[2], &page_ordering_rules));
     }
 
     #[test]
     fn verify_safety_manual_update_should_return_false_for_fourth_updates_in_example_data() {
-        let (page_ordering_rules, all_page_updates) = parse_input(EXAMPLE_DATA);
+        let (page_ordering_rules, all_page_updates) = parse_input(EXAMPLE_DATA).unwrap();
 
         assert!(!verify_safety_manual_update(&all_page_updates[3], &page_ordering_rules));
     }
 
     #[test]
     fn verify_safety_manual_update_should_return_false_for_fifth_updates_in_example_data() {
-        let (page_ordering_rules, all_page_updates) = parse_input(EXAMPLE_DATA);
+        let (page_ordering_rules, all_page_updates) = parse_input(EXAMPLE_DATA).unwrap();
 
         assert!(!verify_safety_manual_update(&all_page_updates[4], &page_ordering_rules));
     }
 
     #[test]
     fn verify_safety_manual_update_should_return_false_for_sixth_updates_in_example_data() {
-        let (page_ordering_rules, all_page_updates) = parse_input(EXAMPLE_DATA);
+        let (page_ordering_rules, all_page_updates) = parse_input(EXAMPLE_DATA).unwrap();
 
         assert!(!verify_safety_manual_update(&all_page_updates[5], &page_ordering_rules));
     }
 
     #[test]
     fn get_middle_page_number_should_return_61_for_first_page_updates() {
-        assert_eq!(61, get_middle_page_number(&vec![75,47,61,53,29]));
+        assert_eq!(61, get_middle_page_number(&[75,47,61,53,29]));
     }
 
     #[test]
     fn sum_middle_page_numbers_of_valid_page_updates_should_return_143_for_example_data() {
-        assert_eq!(143, sum_middle_page_numbers_of_valid_page_updates(EXAMPLE_DATA));
+        let (page_ordering_rules, all_page_updates) = parse_input(EXAMPLE_DATA).unwrap();
+
+        assert_eq!(143, sum_middle_page_numbers_of_valid_page_updates(&page_ordering_rules, &all_page_updates));
     }
 
     #[test]
     fn sum_middle_page_numbers_of_invalid_page_updates_should_return_123_for_example_data() {
-        assert_eq!(123, sum_middle_page_numbers_of_invalid_page_updates(EXAMPLE_DATA));
+        let (page_ordering_rules, all_page_updates) = parse_input(EXAMPLE_DATA).unwrap();
+
+        assert_eq!(123, sum_middle_page_numbers_of_invalid_page_updates(&page_ordering_rules, &all_page_updates));
+    }
+
+    #[test]
+    fn day5_should_solve_both_parts_of_example_data() {
+        let input = Day5.parse(EXAMPLE_DATA).unwrap();
+
+        assert_eq!("143", Day5.part1(&input));
+        assert_eq!("123", Day5.part2(&input));
+    }
+
+    #[test]
+    fn day5_parse_should_fail_for_malformed_update() {
+        assert!(Day5.parse("47|53\n\n75,x,61").is_err());
     }
 }
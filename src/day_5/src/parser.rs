@@ -1,30 +1,37 @@
+use std::str::FromStr;
+
+use aoc_core::solution::SolutionError;
+
 use crate::rule::PageOrderingRule;
 
-pub fn parse_input(puzzle_input: &str) -> (Vec<PageOrderingRule>, Vec<Vec<i32>>) {
+pub fn parse_input(puzzle_input: &str)
+    -> Result<(Vec<PageOrderingRule>, Vec<Vec<i32>>), SolutionError>
+{
     let mut page_ordering_rules: Vec<PageOrderingRule> = vec![];
     let mut page_updates: Vec<Vec<i32>> = vec![];
 
-    let mut line_iter = puzzle_input.lines().peekable();
+    let mut line_iter = puzzle_input.lines();
 
-    while let Some(line) = line_iter.next() {
+    for line in line_iter.by_ref() {
         if line.is_empty() {
             break;
         }
 
-        page_ordering_rules.push(PageOrderingRule::from(line));
+        page_ordering_rules.push(PageOrderingRule::from_str(line)?);
     }
 
-    while let Some(line) = line_iter.next() {
-        page_updates.push(parse_page_updates(line));
+    for line in line_iter {
+        page_updates.push(parse_page_updates(line)?);
     }
 
-    (page_ordering_rules, page_updates)
+    Ok((page_ordering_rules, page_updates))
 }
 
-fn parse_page_updates(line: &str) -> Vec<i32> {
+fn parse_page_updates(line: &str) -> Result<Vec<i32>, SolutionError> {
     line.split(',')
-        .map(|s| s.parse().unwrap())
-        .collect()
+        .map(|s| s.parse())
+        .collect::<Result<_, _>>()
+        .map_err(|_| SolutionError::InvalidInput(format!("'{}' is not a list of page numbers.", line)))
 }
 
 #[cfg(test)]
@@ -35,7 +42,13 @@ mod tests {
 
     #[test]
     fn parse_page_updates_should_parse_test_data() {
-        assert_eq!(vec![75, 47, 61, 53, 29], parse_page_updates(TEST_DATA));
+        assert_eq!(Ok(vec![75, 47, 61, 53, 29]), parse_page_updates(TEST_DATA));
+    }
+
+    #[test]
+    fn parse_page_updates_should_fail_for_empty_update() {
+        assert!(parse_page_updates("").is_err());
+        assert!(parse_page_updates("75,,61").is_err());
     }
 
     const EXAMPLE_DATA: &str = r"47|53
@@ -69,7 +82,7 @@ mod tests {
 
     #[test]
     fn parse_input_should_parse_page_ordering_rules_in_example_data() {
-        let (page_ordering_rules, _) = parse_input(EXAMPLE_DATA);
+        let (page_ordering_rules, _) = parse_input(EXAMPLE_DATA).unwrap();
 
         assert_eq!(
             vec![
@@ -101,7 +114,7 @@ mod tests {
 
     #[test]
     fn parse_input_should_parse_page_updates_in_example_data() {
-        let (_, page_updates) = parse_input(EXAMPLE_DATA);
+        let (_, page_updates) = parse_input(EXAMPLE_DATA).unwrap();
 
         assert_eq!(
             vec![
@@ -114,5 +127,10 @@ mod tests {
             ],
             page_updates
         )
-    }    
+    }
+
+    #[test]
+    fn parse_input_should_fail_for_malformed_rule() {
+        assert!(parse_input("47|53\n97-13\n\n75,47,61").is_err());
+    }
 }
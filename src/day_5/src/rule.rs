@@ -1,3 +1,7 @@
+use std::str::FromStr;
+
+use aoc_core::solution::SolutionError;
+
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct PageOrderingRule {
     pub n1: i32,
@@ -10,25 +14,37 @@ impl PageOrderingRule {
     }
 }
 
-impl From<&str> for PageOrderingRule {
-    fn from(s: &str) -> Self {
-        let tokens: Vec<&str> = s.split('|').collect();
-    
-        Self::new( 
-            tokens[0].parse().unwrap(),
-            tokens[1].parse().unwrap()
-        )
+impl FromStr for PageOrderingRule {
+    type Err = SolutionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SolutionError::InvalidInput(format!("'{}' is not a page ordering rule.", s));
+
+        let (n1, n2) = s.split_once('|').ok_or_else(invalid)?;
+
+        Ok(Self::new(
+            n1.parse().map_err(|_| invalid())?,
+            n2.parse().map_err(|_| invalid())?
+        ))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
     use super::PageOrderingRule;
 
     #[test]
-    fn from_should_parse_47_53() {
+    fn from_str_should_parse_47_53() {
         assert_eq!(
-            PageOrderingRule { n1: 47, n2: 53 },
-            PageOrderingRule::from("47|53"));
+            Ok(PageOrderingRule { n1: 47, n2: 53 }),
+            PageOrderingRule::from_str("47|53"));
+    }
+
+    #[test]
+    fn from_str_should_fail_for_malformed_rule() {
+        assert!(PageOrderingRule::from_str("47").is_err());
+        assert!(PageOrderingRule::from_str("47|x").is_err());
     }
 }
@@ -1,26 +1,41 @@
-use aoc_core::text_map::TextMap;
+use aoc_core::{
+    primitives::{Direction, Position},
+    solution::{Solution, SolutionError},
+    text_map::TextMap,
+};
 
-use anyhow::Result;
+/// The solution of day 6: Guard Gallivant.
+pub struct Day6;
 
-fn main() -> Result<()> {
-    let puzzle_input = aoc_core::get_input(2024, 6)?;
+impl Solution for Day6 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 6;
 
-    let mut map = TextMap::from(puzzle_input.as_str());
+    type Input = TextMap;
 
-    move_guard_till_leaves_map(&mut map);
+    fn parse(&self, puzzle_input: &str) -> Result<Self::Input, SolutionError> {
+        let map = TextMap::try_from(puzzle_input)
+            .map_err(|e| SolutionError::InvalidInput(e.to_string()))?;
 
-    println!(
-        "The guard visits {} distinct positions before leaving the mapped area.",
-        map.count_chars(VISITED));
+        if map.find_char_pos(GUARD_FACING_UP).is_none() {
+            return Err(SolutionError::InvalidInput(
+                format!("The map has no guard facing up ('{}').", GUARD_FACING_UP)));
+        }
+
+        Ok(map)
+    }
 
-    let map = TextMap::from(puzzle_input.as_str());
-    let possible_loops = detect_and_count_possible_loops(&map);
+    fn part1(&self, input: &Self::Input) -> String {
+        let mut map = input.clone();
 
-    println!(
-        "There are {} possible positions to trap the guard in a loop.",
-        possible_loops);
+        move_guard_till_leaves_map(&mut map);
 
-    Ok(())
+        map.count_chars(VISITED).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        detect_and_count_possible_loops(input).to_string()
+    }
 }
 
 const OBSTACLE: char = '#';
@@ -40,13 +55,10 @@ enum GuardState {
 }
 
 fn detect_and_count_possible_loops(map: &TextMap) -> usize {
-    let possibilities_to_check = map.count_chars(WALKABLE);
-    let mut possibilties_checked = 0;
-
     let mut possible_loops = 0;
 
     for y in 0 .. map.height() {
-        for x in 0 .. map.height() {
+        for x in 0 .. map.width() {
             if map.char_at(x, y) == WALKABLE {
                 let mut changed_map = map.clone();
                 changed_map.set_char(x, y, OBSTACLE);
@@ -54,10 +66,6 @@ fn detect_and_count_possible_loops(map: &TextMap) -> usize {
                 if move_guard_and_check_for_loop(&mut changed_map) {
                     possible_loops += 1;
                 }
-
-                possibilties_checked += 1;
-                
-                println!("checked ({}/{})", possibilties_checked, possibilities_to_check);
             }
         }
     }
@@ -73,7 +81,7 @@ fn move_guard_and_check_for_loop(map: &mut TextMap) -> bool {
     guard_path.push((guard.x(), guard.y(), GUARD_FACING_UP));
 
     loop {
-        let guard_state = move_guard(&mut guard, map, &mut guard_path);
+        let guard_state = move_guard(&mut guard, map);
 
         if guard_state == Some(GuardState::LeftMap) {
             return false;
@@ -93,111 +101,59 @@ fn move_guard_and_check_for_loop(map: &mut TextMap) -> bool {
 fn move_guard_till_leaves_map(map: &mut TextMap) {
     let (guard_x, guard_y) = map.find_char_pos(GUARD_FACING_UP).unwrap();
     let mut guard = Guard::new(guard_x, guard_y);
-    let mut guard_path: Vec<(usize, usize, char)> = Vec::new();
 
-    guard_path.push((guard.x(), guard.y(), GUARD_FACING_UP));
-
-    while move_guard(&mut guard, map, &mut guard_path) != Some(GuardState::LeftMap) {}
+    while move_guard(&mut guard, map) != Some(GuardState::LeftMap) {}
 }
 
-fn move_guard(guard: &mut Guard, map: &mut TextMap, guard_path: &mut Vec<(usize, usize, char)>) -> Option<GuardState> {
-    let guard_x = guard.x();
-    let guard_y = guard.y();
+fn move_guard(guard: &mut Guard, map: &mut TextMap) -> Option<GuardState> {
+    let direction = map.get(Position::new(guard.x(), guard.y()))
+        .and_then(|&c| guard_direction(c))?;
 
-    match map.char_at(guard_x, guard_y) {
-        GUARD_FACING_UP => Some(up(guard, map)),
-        GUARD_FACING_RIGHT => Some(right(guard, map)),
-        GUARD_FACING_DOWN => Some(down(guard, map)),
-        GUARD_FACING_LEFT => Some(left(guard, map)),
-        _ => None,
-    }
-}
-
-fn up(guard: &mut Guard, map: &mut TextMap) -> GuardState {
-    let guard_x = guard.x();
-    let guard_y = guard.y();
-
-    if guard_y == 0 {
-        map.set_char(guard_x, guard_y, VISITED);
-
-        GuardState::LeftMap
-    } else if map.char_at(guard_x, guard_y - 1) == OBSTACLE {
-        map.set_char(guard_x, guard_y, GUARD_FACING_RIGHT);
-
-        GuardState::Turned
-    } else {
-        map.set_char(guard_x, guard_y, VISITED);
-        map.set_char(guard_x, guard_y - 1, GUARD_FACING_UP);
-
-        guard.set_pos(guard_x, guard_y - 1);
-
-        GuardState::Moved
-    }
+    Some(step(guard, map, direction))
 }
 
-fn right(guard: &mut Guard, map: &mut TextMap) -> GuardState {
-    let guard_x = guard.x();
-    let guard_y = guard.y();
+fn step(guard: &mut Guard, map: &mut TextMap, direction: Direction) -> GuardState {
+    let pos = Position::new(guard.x(), guard.y());
 
-    if guard_x == map.width() - 1 {
-        map.set_char(guard_x, guard_y, VISITED);
+    match pos.checked_step(direction, map.bounds()) {
+        None => {
+            map.set(pos, VISITED);
 
-        GuardState::LeftMap
-    } else if map.char_at(guard_x + 1, guard_y) == OBSTACLE {
-        map.set_char(guard_x, guard_y, GUARD_FACING_DOWN);
+            GuardState::LeftMap
+        }
+        Some(next) if map.get(next) == Some(&OBSTACLE) => {
+            map.set(pos, guard_char(direction.turn_right()));
 
-        GuardState::Turned
-    } else {
-        map.set_char(guard_x, guard_y, VISITED);
-        map.set_char(guard_x + 1, guard_y, GUARD_FACING_RIGHT);
+            GuardState::Turned
+        }
+        Some(next) => {
+            map.set(pos, VISITED);
+            map.set(next, guard_char(direction));
 
-        guard.set_pos(guard_x + 1, guard_y);
+            guard.set_pos(next.x, next.y);
 
-        GuardState::Moved
+            GuardState::Moved
+        }
     }
 }
 
-fn down(guard: &mut Guard, map: &mut TextMap) -> GuardState {
-    let guard_x = guard.x();
-    let guard_y = guard.y();
-
-    if guard_y == map.height() - 1 {
-        map.set_char(guard_x, guard_y, VISITED);
-
-        GuardState::LeftMap
-    } else if map.char_at(guard_x, guard_y + 1) == OBSTACLE {
-        map.set_char(guard_x, guard_y, GUARD_FACING_LEFT);
-
-        GuardState::Turned
-    } else {
-        map.set_char(guard_x, guard_y, VISITED);
-        map.set_char(guard_x, guard_y + 1, GUARD_FACING_DOWN);
-
-        guard.set_pos(guard_x, guard_y + 1);
-
-        GuardState::Moved
+fn guard_direction(c: char) -> Option<Direction> {
+    match c {
+        GUARD_FACING_UP => Some(Direction::North),
+        GUARD_FACING_RIGHT => Some(Direction::East),
+        GUARD_FACING_DOWN => Some(Direction::South),
+        GUARD_FACING_LEFT => Some(Direction::West),
+        _ => None,
     }
 }
 
-fn left(guard: &mut Guard, map: &mut TextMap) -> GuardState {
-    let guard_x = guard.x();
-    let guard_y = guard.y();
-
-    if guard_x == 0 {
-        map.set_char(guard_x, guard_y, VISITED);
-
-        GuardState::LeftMap
-    } else if map.char_at(guard_x - 1, guard_y) == OBSTACLE {
-        map.set_char(guard_x, guard_y, GUARD_FACING_UP);
-
-        GuardState::Turned
-    } else {
-        map.set_char(guard_x, guard_y, VISITED);
-        map.set_char(guard_x - 1, guard_y, GUARD_FACING_LEFT);
-
-        guard.set_pos(guard_x - 1, guard_y);
-
-        GuardState::Moved
+fn guard_char(direction: Direction) -> char {
+    match direction {
+        Direction::North => GUARD_FACING_UP,
+        Direction::East => GUARD_FACING_RIGHT,
+        Direction::South => GUARD_FACING_DOWN,
+        Direction::West => GUARD_FACING_LEFT,
+        _ => panic!("The guard can not face {:?}!", direction),
     }
 }
 
@@ -227,9 +183,9 @@ impl Guard {
 
 #[cfg(test)]
 mod tests {
-    use aoc_core::text_map::TextMap;
+    use aoc_core::{primitives::Direction, solution::Solution, text_map::TextMap};
 
-    use crate::{detect_and_count_possible_loops, down, left, move_guard_and_check_for_loop, move_guard_till_leaves_map, right, up, Guard, GuardState, GUARD_FACING_DOWN, GUARD_FACING_LEFT, GUARD_FACING_RIGHT, GUARD_FACING_UP, VISITED};
+    use crate::{Day6, detect_and_count_possible_loops, move_guard_and_check_for_loop, move_guard_till_leaves_map, step, Guard, GuardState, GUARD_FACING_DOWN, GUARD_FACING_LEFT, GUARD_FACING_RIGHT, GUARD_FACING_UP, VISITED};
 
     const EXAMPLE_DATA: &str = r"....#.....
 .........#
@@ -244,11 +200,11 @@ mod tests {
 
     #[test]
     fn up_is_moving_guard_up() {
-        let mut map = TextMap::from(EXAMPLE_DATA);
+        let mut map = TextMap::try_from(EXAMPLE_DATA).unwrap();
         let (guard_x, guard_y) = map.find_char_pos(GUARD_FACING_UP).unwrap();
         let mut guard = Guard::new(guard_x, guard_y);
 
-        assert_eq!(GuardState::Moved, up(&mut guard, &mut map));
+        assert_eq!(GuardState::Moved, step(&mut guard, &mut map, Direction::North));
 
         assert_eq!(guard_x, guard.x());
         assert_eq!(guard_y - 1, guard.y());
@@ -260,12 +216,12 @@ mod tests {
 
     #[test]
     fn up_is_turning_right_on_obstacle() {
-        let mut map = TextMap::from(EXAMPLE_DATA);
+        let mut map = TextMap::try_from(EXAMPLE_DATA).unwrap();
         let guard_x = 4;
         let guard_y = 1;
         let mut guard = Guard::new(guard_x, guard_y);
 
-        assert_eq!(GuardState::Turned, up(&mut guard, &mut map));
+        assert_eq!(GuardState::Turned, step(&mut guard, &mut map, Direction::North));
 
         assert_eq!(guard_x, guard.x());
         assert_eq!(guard_y, guard.y());
@@ -275,12 +231,12 @@ mod tests {
 
     #[test]
     fn up_is_leaving_map() {
-        let mut map = TextMap::from(EXAMPLE_DATA);
+        let mut map = TextMap::try_from(EXAMPLE_DATA).unwrap();
         let guard_x = 3;
         let guard_y = 0;
         let mut guard = Guard::new(guard_x, guard_y);
 
-        assert_eq!(GuardState::LeftMap, up(&mut guard, &mut map));
+        assert_eq!(GuardState::LeftMap, step(&mut guard, &mut map, Direction::North));
 
         assert_eq!(guard_x, guard.x());
         assert_eq!(guard_y, guard.y());
@@ -290,11 +246,11 @@ mod tests {
 
     #[test]
     fn right_is_moving_guard_right() {
-        let mut map = TextMap::from(EXAMPLE_DATA);
+        let mut map = TextMap::try_from(EXAMPLE_DATA).unwrap();
         let (guard_x, guard_y) = map.find_char_pos(GUARD_FACING_UP).unwrap();
         let mut guard = Guard::new(guard_x, guard_y);
 
-        assert_eq!(GuardState::Moved, right(&mut guard, &mut map));
+        assert_eq!(GuardState::Moved, step(&mut guard, &mut map, Direction::East));
 
         assert_eq!(guard_x + 1, guard.x());
         assert_eq!(guard_y, guard.y());
@@ -305,12 +261,12 @@ mod tests {
 
     #[test]
     fn right_is_turning_down_on_obstacle() {
-        let mut map = TextMap::from(EXAMPLE_DATA);
+        let mut map = TextMap::try_from(EXAMPLE_DATA).unwrap();
         let guard_x = 8;
         let guard_y = 1;
         let mut guard = Guard::new(guard_x, guard_y);
 
-        assert_eq!(GuardState::Turned, right(&mut guard, &mut map));
+        assert_eq!(GuardState::Turned, step(&mut guard, &mut map, Direction::East));
 
         assert_eq!(guard_x, guard.x());
         assert_eq!(guard_y, guard.y());
@@ -320,12 +276,12 @@ mod tests {
 
     #[test]
     fn right_is_leaving_map() {
-        let mut map = TextMap::from(EXAMPLE_DATA);
+        let mut map = TextMap::try_from(EXAMPLE_DATA).unwrap();
         let guard_x = 9;
         let guard_y = 2;
         let mut guard = Guard::new(guard_x, guard_y);
 
-        assert_eq!(GuardState::LeftMap, right(&mut guard, &mut map));
+        assert_eq!(GuardState::LeftMap, step(&mut guard, &mut map, Direction::East));
 
         assert_eq!(guard_x, guard.x());
         assert_eq!(guard_y, guard.y());
@@ -335,11 +291,11 @@ mod tests {
 
     #[test]
     fn down_is_moving_guard_down() {
-        let mut map = TextMap::from(EXAMPLE_DATA);
+        let mut map = TextMap::try_from(EXAMPLE_DATA).unwrap();
         let (guard_x, guard_y) = map.find_char_pos(GUARD_FACING_UP).unwrap();
         let mut guard = Guard::new(guard_x, guard_y);
 
-        assert_eq!(GuardState::Moved, down(&mut guard, &mut map));
+        assert_eq!(GuardState::Moved, step(&mut guard, &mut map, Direction::South));
 
         assert_eq!(guard_x, guard.x());
         assert_eq!(guard_y + 1, guard.y());
@@ -350,12 +306,12 @@ mod tests {
 
     #[test]
     fn down_is_turning_left_on_obstacle() {
-        let mut map = TextMap::from(EXAMPLE_DATA);
+        let mut map = TextMap::try_from(EXAMPLE_DATA).unwrap();
         let guard_x = 6;
         let guard_y = 8;
         let mut guard = Guard::new(guard_x, guard_y);
 
-        assert_eq!(GuardState::Turned, down(&mut guard, &mut map));
+        assert_eq!(GuardState::Turned, step(&mut guard, &mut map, Direction::South));
 
         assert_eq!(guard_x, guard.x());
         assert_eq!(guard_y, guard.y());
@@ -365,12 +321,12 @@ mod tests {
 
     #[test]
     fn down_is_leaving_map() {
-        let mut map = TextMap::from(EXAMPLE_DATA);
+        let mut map = TextMap::try_from(EXAMPLE_DATA).unwrap();
         let guard_x = 4;
         let guard_y = 9;
         let mut guard = Guard::new(guard_x, guard_y);
 
-        assert_eq!(GuardState::LeftMap, down(&mut guard, &mut map));
+        assert_eq!(GuardState::LeftMap, step(&mut guard, &mut map, Direction::South));
 
         assert_eq!(guard_x, guard.x());
         assert_eq!(guard_y, guard.y());
@@ -380,11 +336,11 @@ mod tests {
 
     #[test]
     fn left_is_moving_guard_left() {
-        let mut map = TextMap::from(EXAMPLE_DATA);
+        let mut map = TextMap::try_from(EXAMPLE_DATA).unwrap();
         let (guard_x, guard_y) = map.find_char_pos(GUARD_FACING_UP).unwrap();
         let mut guard = Guard::new(guard_x, guard_y);
 
-        assert_eq!(GuardState::Moved, left(&mut guard, &mut map));
+        assert_eq!(GuardState::Moved, step(&mut guard, &mut map, Direction::West));
 
         assert_eq!(guard_x - 1, guard.x());
         assert_eq!(guard_y, guard.y());
@@ -395,12 +351,12 @@ mod tests {
 
     #[test]
     fn left_is_turning_up_on_obstacle() {
-        let mut map = TextMap::from(EXAMPLE_DATA);
+        let mut map = TextMap::try_from(EXAMPLE_DATA).unwrap();
         let guard_x = 3;
         let guard_y = 3;
         let mut guard = Guard::new(guard_x, guard_y);
 
-        assert_eq!(GuardState::Turned, left(&mut guard, &mut map));
+        assert_eq!(GuardState::Turned, step(&mut guard, &mut map, Direction::West));
 
         assert_eq!(guard_x, guard.x());
         assert_eq!(guard_y, guard.y());
@@ -410,12 +366,12 @@ mod tests {
 
     #[test]
     fn left_is_leaving_map() {
-        let mut map = TextMap::from(EXAMPLE_DATA);
+        let mut map = TextMap::try_from(EXAMPLE_DATA).unwrap();
         let guard_x = 0;
         let guard_y = 2;
         let mut guard = Guard::new(guard_x, guard_y);
 
-        assert_eq!(GuardState::LeftMap, left(&mut guard, &mut map));
+        assert_eq!(GuardState::LeftMap, step(&mut guard, &mut map, Direction::West));
 
         assert_eq!(guard_x, guard.x());
         assert_eq!(guard_y, guard.y());
@@ -436,16 +392,16 @@ const VISITED_LOCATIONS: &str = r"....#.....
 
     #[test]
     fn move_guard_till_leaves_map_should_mark_all_visited_locations_for_example_data() {
-        let mut map = TextMap::from(EXAMPLE_DATA);
+        let mut map = TextMap::try_from(EXAMPLE_DATA).unwrap();
 
         move_guard_till_leaves_map(&mut map);
 
-        assert_eq!(TextMap::from(VISITED_LOCATIONS), map);
+        assert_eq!(TextMap::try_from(VISITED_LOCATIONS).unwrap(), map);
     }
 
     #[test]
     fn count_chars_should_return_41_for_example_data() {
-        let mut map = TextMap::from(EXAMPLE_DATA);
+        let mut map = TextMap::try_from(EXAMPLE_DATA).unwrap();
 
         move_guard_till_leaves_map(&mut map);
 
@@ -465,14 +421,14 @@ const EXAMPLE_DATA_LOOP_1: &str = r"....#.....
 
     #[test]
     fn move_guard_and_check_for_loop_should_return_true_for_loop_1() {
-        let mut map = TextMap::from(EXAMPLE_DATA_LOOP_1);
+        let mut map = TextMap::try_from(EXAMPLE_DATA_LOOP_1).unwrap();
 
         assert!(move_guard_and_check_for_loop(&mut map));
     }
 
     #[test]
     fn move_guard_and_check_for_loop_should_return_false_for_example_data() {
-        let mut map = TextMap::from(EXAMPLE_DATA);
+        let mut map = TextMap::try_from(EXAMPLE_DATA).unwrap();
 
         assert!(!move_guard_and_check_for_loop(&mut map));
     }
@@ -490,15 +446,40 @@ const EXAMPLE_DATA_LOOP_2: &str = r"....#.....
 
     #[test]
     fn move_guard_and_check_for_loop_should_return_true_for_loop_2() {
-        let mut map = TextMap::from(EXAMPLE_DATA_LOOP_2);
+        let mut map = TextMap::try_from(EXAMPLE_DATA_LOOP_2).unwrap();
 
         assert!(move_guard_and_check_for_loop(&mut map));
     }
 
     #[test]
     fn detect_and_count_possible_loops_should_return_6_for_example_data() {
-        let map = TextMap::from(EXAMPLE_DATA);
+        let map = TextMap::try_from(EXAMPLE_DATA).unwrap();
 
         assert_eq!(6, detect_and_count_possible_loops(&map))
     }
+
+    #[test]
+    fn detect_and_count_possible_loops_should_check_all_columns_of_wide_map() {
+        let map = TextMap::try_from(".##...\n.^....\n.#....\n.###.#").unwrap();
+
+        assert_eq!(2, detect_and_count_possible_loops(&map))
+    }
+
+    #[test]
+    fn day6_should_solve_both_parts_of_example_data() {
+        let input = Day6.parse(EXAMPLE_DATA).unwrap();
+
+        assert_eq!("41", Day6.part1(&input));
+        assert_eq!("6", Day6.part2(&input));
+    }
+
+    #[test]
+    fn day6_parse_should_fail_for_ragged_map() {
+        assert!(Day6.parse("..#\n.^\n...").is_err());
+    }
+
+    #[test]
+    fn day6_parse_should_fail_for_map_without_guard() {
+        assert!(Day6.parse("..#\n...\n#..").is_err());
+    }
 }
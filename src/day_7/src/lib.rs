@@ -1,35 +1,35 @@
-use std::time::Instant;
+use std::str::FromStr;
 
-use anyhow::Result;
+use aoc_core::solution::{Solution, SolutionError, Variant};
 
-fn main() -> Result<()> {
-    let puzzle_input = aoc_core::get_input(2024, 7)?;
-    
-    let start = Instant::now();
-    
-    let test_equations = parse_calibration_equations(&puzzle_input);
-
-    let duration = start.elapsed();
-
-    println!("Parsing took {:?}", duration);
+/// The solution of day 7: Bridge Repair.
+pub struct Day7;
 
-    let start = Instant::now();
+impl Solution for Day7 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 7;
 
-    let sum = sum_of_valid_test_equations(&test_equations);
+    type Input = Vec<TestEquation>;
 
-    let duration = start.elapsed();
-
-    println!("The total calibration result is: {} (took {:?})", sum, duration);
-
-    let start = Instant::now();
-
-    let sum = sum_of_valid_test_equations_recursive(&test_equations, true);
+    fn parse(&self, puzzle_input: &str) -> Result<Self::Input, SolutionError> {
+        parse_calibration_equations(puzzle_input)
+    }
 
-    let duration = start.elapsed();
+    fn part1(&self, input: &Self::Input) -> String {
+        sum_of_valid_test_equations(input).to_string()
+    }
 
-    println!("The total calibration result is: {} (recursive variant took {:?})", sum, duration);
+    fn part2(&self, input: &Self::Input) -> String {
+        sum_of_valid_test_equations_recursive(input, true).to_string()
+    }
 
-    Ok(())
+    fn variants(&self) -> Vec<Variant<Self>> {
+        vec![Variant {
+            name: "recursive",
+            part: 1,
+            solve: |_, input| sum_of_valid_test_equations_recursive(input, false).to_string(),
+        }]
+    }
 }
 
 fn sum_of_valid_test_equations(test_equations: &[TestEquation]) -> i64 {
@@ -46,8 +46,8 @@ fn sum_of_valid_test_equations_recursive(test_equations: &[TestEquation], use_co
         .sum()
 }
 
-fn parse_calibration_equations(input: &str) -> Vec<TestEquation> {
-    input.lines().map(|line| TestEquation::from(line)).collect()
+fn parse_calibration_equations(input: &str) -> Result<Vec<TestEquation>, SolutionError> {
+    input.lines().map(TestEquation::from_str).collect()
 }
 
 // XXX: Operators are always evaluated left-to-right, not according to precedence rules.
@@ -59,7 +59,7 @@ fn parse_calibration_equations(input: &str) -> Vec<TestEquation> {
 //     earlier backtracking
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-struct TestEquation {
+pub struct TestEquation {
     result: i64,
     operands: Vec<i64>,
 }
@@ -168,29 +168,36 @@ impl TestEquation {
     }
 }
 
-impl From<&str> for TestEquation {
-    fn from(value: &str) -> Self {
-        let pos_colon = value.chars().position(|c| c == ':')
-            .expect("could not find a colon in the string");
+impl FromStr for TestEquation {
+    type Err = SolutionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SolutionError::InvalidInput(format!("'{}' is not a test equation.", s));
 
-        let result = value[ .. pos_colon].parse()
-            .expect("could not parse the equation result");
+        let (result, operands) = s.split_once(':').ok_or_else(invalid)?;
 
-        let operands = value[ pos_colon + 1 .. ]
+        let result = result.parse().map_err(|_| invalid())?;
+
+        let operands = operands
             .split_whitespace()
-            .map(|o| o.parse().unwrap())
-            .collect();
+            .map(|o| o.parse())
+            .collect::<Result<_, _>>()
+            .map_err(|_| invalid())?;
 
-        Self {
+        Ok(Self {
             result,
             operands,
-        }
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse_calibration_equations, sum_of_valid_test_equations, sum_of_valid_test_equations_recursive, TestEquation};
+    use std::str::FromStr;
+
+    use aoc_core::solution::{solve_part, Solution};
+
+    use crate::{Day7, parse_calibration_equations, sum_of_valid_test_equations, sum_of_valid_test_equations_recursive, TestEquation};
 
     const FIRST_EXAMPLE: &str = "190: 10 19";
 
@@ -201,10 +208,17 @@ mod tests {
                 result: 190,
                 operands: vec![10, 19],
             },
-            TestEquation::from(FIRST_EXAMPLE)
+            TestEquation::from_str(FIRST_EXAMPLE).unwrap()
         );
     }
 
+    #[test]
+    fn from_str_should_fail_for_malformed_equation() {
+        assert!(TestEquation::from_str("190 10 19").is_err());
+        assert!(TestEquation::from_str("x: 10 19").is_err());
+        assert!(TestEquation::from_str("190: 10 y").is_err());
+    }
+
     const SECOND_EXAMPLE: &str = "3267: 81 40 27";
 
     #[test]
@@ -214,19 +228,19 @@ mod tests {
                 result: 3267,
                 operands: vec![81, 40, 27],
             },
-            TestEquation::from(SECOND_EXAMPLE)
+            TestEquation::from_str(SECOND_EXAMPLE).unwrap()
         );
     }
 
     #[test]
     fn is_valid_should_return_true_for_first_example() {
-        let test_equation = TestEquation::from(FIRST_EXAMPLE);
+        let test_equation = TestEquation::from_str(FIRST_EXAMPLE).unwrap();
         assert!(test_equation.is_valid());
     }
 
     #[test]
     fn is_valid_should_return_true_for_second_example() {
-        let test_equation = TestEquation::from(SECOND_EXAMPLE);
+        let test_equation = TestEquation::from_str(SECOND_EXAMPLE).unwrap();
         assert!(test_equation.is_valid());
     }
 
@@ -234,7 +248,7 @@ mod tests {
 
     #[test]
     fn is_valid_should_return_false_for_third_example() {
-        let test_equation = TestEquation::from(THIRD_EXAMPLE);
+        let test_equation = TestEquation::from_str(THIRD_EXAMPLE).unwrap();
         assert!(!test_equation.is_valid());
     }
 
@@ -250,13 +264,13 @@ mod tests {
 
     #[test]
     fn sum_of_valid_test_equations_should_return_3749_for_example_data() {
-        let test_equations = parse_calibration_equations(EXAMPLE_DATA);
+        let test_equations = parse_calibration_equations(EXAMPLE_DATA).unwrap();
         assert_eq!(3749, sum_of_valid_test_equations(&test_equations));
     }
 
     #[test]
     fn all_combinations_recursive_should_return_two_results_for_first_example() {
-        let test_equation = TestEquation::from(FIRST_EXAMPLE);
+        let test_equation = TestEquation::from_str(FIRST_EXAMPLE).unwrap();
         let mut operands = test_equation.operands.clone();
 
         operands.reverse();
@@ -271,7 +285,7 @@ mod tests {
 
     #[test]
     fn all_combinations_recursive_should_return_four_results_for_second_example() {
-        let test_equation = TestEquation::from(SECOND_EXAMPLE);
+        let test_equation = TestEquation::from_str(SECOND_EXAMPLE).unwrap();
         let mut operands = test_equation.operands.clone();
 
         operands.reverse();
@@ -288,7 +302,7 @@ mod tests {
 
     #[test]
     fn all_combinations_recursive_should_return_two_results_for_third_example() {
-        let test_equation = TestEquation::from(THIRD_EXAMPLE);
+        let test_equation = TestEquation::from_str(THIRD_EXAMPLE).unwrap();
         let mut operands = test_equation.operands.clone();
 
         operands.reverse();
@@ -303,25 +317,47 @@ mod tests {
 
     #[test]
     fn is_valid_rescursive_should_return_true_for_first_example() {
-        let test_equation = TestEquation::from(FIRST_EXAMPLE);
+        let test_equation = TestEquation::from_str(FIRST_EXAMPLE).unwrap();
         assert!(test_equation.is_valid_recursive(false));
     }
 
     #[test]
     fn is_valid_recursive_should_return_true_for_second_example() {
-        let test_equation = TestEquation::from(SECOND_EXAMPLE);
+        let test_equation = TestEquation::from_str(SECOND_EXAMPLE).unwrap();
         assert!(test_equation.is_valid_recursive(false));
     }
 
     #[test]
     fn is_valid_recursive_should_return_false_for_third_example() {
-        let test_equation = TestEquation::from(THIRD_EXAMPLE);
+        let test_equation = TestEquation::from_str(THIRD_EXAMPLE).unwrap();
         assert!(!test_equation.is_valid_recursive(false));
     }
 
     #[test]
     fn sum_of_valid_test_recursive_equations_should_return_3749_for_example_data() {
-        let test_equations = parse_calibration_equations(EXAMPLE_DATA);
+        let test_equations = parse_calibration_equations(EXAMPLE_DATA).unwrap();
         assert_eq!(3749, sum_of_valid_test_equations_recursive(&test_equations, false));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn day7_should_solve_both_parts_of_example_data() {
+        let input = Day7.parse(EXAMPLE_DATA).unwrap();
+
+        assert_eq!("3749", Day7.part1(&input));
+        assert_eq!("11387", Day7.part2(&input));
+    }
+
+    #[test]
+    fn variants_should_return_same_results_as_parts() {
+        let input = Day7.parse(EXAMPLE_DATA).unwrap();
+
+        for variant in Day7.variants() {
+            assert_eq!(solve_part(&Day7, &input, variant.part).unwrap(), (variant.solve)(&Day7, &input));
+        }
+    }
+
+    #[test]
+    fn day7_parse_should_fail_for_malformed_line() {
+        assert!(Day7.parse("190: 10 19\n3267 81 40 27").is_err());
+    }
+}